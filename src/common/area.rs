@@ -88,12 +88,30 @@ where
         Pos2::new(self.upper_left.x(), self.lower_right.y())
     }
 
+    /// all four corners, clockwise starting at the upper left
+    pub fn corners(&self) -> [Pos2<T>; 4] {
+        [
+            self.upper_left(),
+            self.upper_right(),
+            self.lower_right(),
+            self.lower_left(),
+        ]
+    }
+
     pub fn widen(self, inc: T) -> Self {
         Self::new(
             self.lower_right - Pos2::splat(inc),
             self.upper_left + Pos2::splat(inc),
         )
     }
+
+    pub fn translate(&self, offset: Pos2<T>) -> Area<T> {
+        Self::new(self.lower_right + offset, self.upper_left + offset)
+    }
+
+    pub fn scale(&self, factor: T) -> Area<T> {
+        Self::new(self.lower_right * factor, self.upper_left * factor)
+    }
 }
 
 impl<T> Area<T>
@@ -110,13 +128,24 @@ impl<'a, T> Area<T>
 where
     T: Num + Ord + 'a + Copy,
 {
-    pub fn from_iterator<I>(mut iter: I) -> Option<Self>
+    pub fn from_iterator<I>(iter: I) -> Option<Self>
     where
         I: Iterator<Item = &'a Pos2<T>>,
     {
-        let first = *iter.next()?;
+        iter.copied().collect()
+    }
+}
+
+/// the bounding area of all points, None if there are none
+impl<T> FromIterator<Pos2<T>> for Option<Area<T>>
+where
+    T: Num + Ord + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = Pos2<T>>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
         let (upper, lower) = iter.fold((first, first), |(mx, mn), p| {
-            (mx.max_components(*p), mn.min_components(*p))
+            (mx.max_components(p), mn.min_components(p))
         });
 
         Some(Area::new(lower, upper))
@@ -205,7 +234,7 @@ where
     fn new(area: &'a Area<T>, ascending: bool) -> RowIterator<'a, T> {
         RowIterator {
             area,
-            row: if ascending { area.bottom() } else { area.top() },
+            row: if ascending { area.top() } else { area.bottom() },
             ascending,
         }
     }
@@ -218,8 +247,8 @@ where
     type Item = Row<'a, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if (self.ascending && self.row >= self.area.top())
-            || (!self.ascending && self.row <= self.area.bottom())
+        if (self.ascending && self.row <= self.area.bottom())
+            || (!self.ascending && self.row >= self.area.top())
         {
             let row = Row {
                 area: self.area,
@@ -314,9 +343,9 @@ where
 {
     pub fn new(area: &'a Area<T>, ascending: bool) -> CellIterator<'a, T> {
         let (col, row) = if ascending {
-            (area.left(), area.bottom())
+            (area.left(), area.top())
         } else {
-            (area.right(), area.top())
+            (area.right(), area.bottom())
         };
         CellIterator {
             area,
@@ -334,20 +363,20 @@ where
     type Item = Pos2<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if (self.ascending && self.row >= self.area.top())
-            || (!self.ascending && self.row <= self.area.bottom())
+        if (self.ascending && self.row <= self.area.bottom())
+            || (!self.ascending && self.row >= self.area.top())
         {
             let pos = Pos2::new(self.col, self.row);
             if self.ascending {
                 self.col += T::one();
                 if self.col > self.area.right() {
-                    self.row -= T::one();
+                    self.row += T::one();
                     self.col = self.area.left();
                 }
             } else {
                 self.col -= T::one();
                 if self.col < self.area.left() {
-                    self.row += T::one();
+                    self.row -= T::one();
                     self.col = self.area.right();
                 }
             }
@@ -363,6 +392,17 @@ where
 mod test {
     use super::*;
 
+    #[test]
+    fn collect_points() {
+        let points = [Pos2::new(3, -1), Pos2::new(-2, 4), Pos2::new(0, 2)];
+        let area: Option<Area<i32>> = points.iter().copied().collect();
+        assert_eq!(area, Some(Area::new(Pos2::new(-2, -1), Pos2::new(3, 4))));
+        assert_eq!(area, Area::from_iterator(points.iter()));
+
+        let none: Option<Area<i32>> = Vec::new().into_iter().collect();
+        assert_eq!(none, None);
+    }
+
     #[test]
     fn test_cell_iterator() {
         let area = Area::new(Pos2::new(-1, -1), Pos2::new(1, 1));
@@ -379,5 +419,82 @@ mod test {
             Pos2::new(1, 1),
         ];
         assert_eq!(result, expected);
+
+        let result = area.cells(false).collect::<Vec<_>>();
+        assert_eq!(result, expected.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_row_iterator() {
+        let area = Area::new(Pos2::new(-1, 2), Pos2::new(0, 4));
+        let rows = area
+            .rows(true)
+            .map(|row| row.cols(true).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                [Pos2::new(-1, 2), Pos2::new(0, 2)],
+                [Pos2::new(-1, 3), Pos2::new(0, 3)],
+                [Pos2::new(-1, 4), Pos2::new(0, 4)],
+            ]
+        );
+
+        let rows = area
+            .rows(false)
+            .map(|row| row.cols(false).next())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                Some(Pos2::new(0, 4)),
+                Some(Pos2::new(0, 3)),
+                Some(Pos2::new(0, 2))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_translate() {
+        let area = Area::new(Pos2::new(-1, 0), Pos2::new(2, 4));
+        let result = area.translate(Pos2::new(2, 3));
+        assert_eq!(result.upper_left(), Pos2::new(1, 3));
+        assert_eq!(result.lower_right(), Pos2::new(4, 7));
+        assert_eq!(result.width(), area.width());
+        assert_eq!(result.height(), area.height());
+    }
+
+    #[test]
+    fn test_scale() {
+        let area = Area::new(Pos2::new(1, 2), Pos2::new(4, 6));
+        let result = area.scale(2);
+        assert_eq!(result.upper_left(), Pos2::new(2, 4));
+        assert_eq!(result.lower_right(), Pos2::new(8, 12));
+        assert_eq!(
+            result.right() - result.left(),
+            2 * (area.right() - area.left())
+        );
+        assert_eq!(
+            result.bottom() - result.top(),
+            2 * (area.bottom() - area.top())
+        );
+
+        let result = area.scale(-1);
+        assert_eq!(result.upper_left(), Pos2::new(-4, -6));
+        assert_eq!(result.lower_right(), Pos2::new(-1, -2));
+    }
+
+    #[test]
+    fn test_corners() {
+        let area = Area::new(Pos2::new(4, -1), Pos2::new(-2, 3));
+        assert_eq!(
+            area.corners(),
+            [
+                Pos2::new(-2, -1),
+                Pos2::new(4, -1),
+                Pos2::new(4, 3),
+                Pos2::new(-2, 3),
+            ]
+        );
     }
 }
@@ -148,6 +148,35 @@ where
         let two = T::one() + T::one();
         (self.lower + self.upper) / two
     }
+
+    /// all eight vertices, x changing fastest, then y, then z
+    pub fn corners(&self) -> [Pos3<T>; 8] {
+        let lower = self.lower;
+        let upper = self.upper;
+        [
+            lower,
+            lower.set_x(upper.x()),
+            upper.set_z(lower.z()).set_x(lower.x()),
+            upper.set_z(lower.z()),
+            lower.set_z(upper.z()),
+            upper.set_y(lower.y()),
+            upper.set_x(lower.x()),
+            upper,
+        ]
+    }
+
+    pub fn faces(&self) -> [(Pos3<T>, Pos3<T>); 6] {
+        let lower = self.lower;
+        let upper = self.upper;
+        [
+            (lower, upper.set_x(lower.x())),
+            (lower.set_x(upper.x()), upper),
+            (lower, upper.set_y(lower.y())),
+            (lower.set_y(upper.y()), upper),
+            (lower, upper.set_z(lower.z())),
+            (lower.set_z(upper.z()), upper),
+        ]
+    }
 }
 
 impl<T> Add<Pos3<T>> for Block<T>
@@ -176,13 +205,24 @@ impl<'a, T> Block<T>
 where
     T: Num + Ord + 'a + Copy,
 {
-    pub fn from_iterator<I>(mut iter: I) -> Option<Self>
+    pub fn from_iterator<I>(iter: I) -> Option<Self>
     where
         I: Iterator<Item = &'a Pos3<T>>,
     {
-        let first = *iter.next()?;
+        iter.copied().collect()
+    }
+}
+
+/// the bounding block of all points, None if there are none
+impl<T> FromIterator<Pos3<T>> for Option<Block<T>>
+where
+    T: Num + Ord + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = Pos3<T>>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
         let (upper, lower) = iter.fold((first, first), |(mx, mn), p| {
-            (mx.max_components(*p), mn.min_components(*p))
+            (mx.max_components(p), mn.min_components(p))
         });
 
         Some(Block::new(lower, upper))
@@ -238,3 +278,48 @@ where
         write!(f, "[{}-{}]", self.lower, self.upper)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn collect_points() {
+        let points = vec![Pos3::new(1, 5, -3), Pos3::new(4, 0, 2)];
+        let block: Option<Block<i32>> = points.into_iter().collect();
+        assert_eq!(
+            block,
+            Some(Block::new(Pos3::new(1, 0, -3), Pos3::new(4, 5, 2)))
+        );
+    }
+
+    #[test]
+    fn test_faces() {
+        let block = Block::new(Pos3::new(0, 0, 0), Pos3::new(1, 2, 3));
+        let expected = [
+            (Pos3::new(0, 0, 0), Pos3::new(0, 2, 3)),
+            (Pos3::new(1, 0, 0), Pos3::new(1, 2, 3)),
+            (Pos3::new(0, 0, 0), Pos3::new(1, 0, 3)),
+            (Pos3::new(0, 2, 0), Pos3::new(1, 2, 3)),
+            (Pos3::new(0, 0, 0), Pos3::new(1, 2, 0)),
+            (Pos3::new(0, 0, 3), Pos3::new(1, 2, 3)),
+        ];
+        assert_eq!(block.faces(), expected);
+    }
+
+    #[test]
+    fn test_corners() {
+        let block = Block::new(Pos3::new(1, 1, 1), Pos3::new(0, 0, 0));
+        let expected = [
+            Pos3::new(0, 0, 0),
+            Pos3::new(1, 0, 0),
+            Pos3::new(0, 1, 0),
+            Pos3::new(1, 1, 0),
+            Pos3::new(0, 0, 1),
+            Pos3::new(1, 0, 1),
+            Pos3::new(0, 1, 1),
+            Pos3::new(1, 1, 1),
+        ];
+        assert_eq!(block.corners(), expected);
+    }
+}
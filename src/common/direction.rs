@@ -116,6 +116,16 @@ impl Direction {
     pub fn from_bit(value: u8) -> impl Iterator<Item = Self> {
         Self::iter().filter(move |d| d.as_bit() & value != 0)
     }
+
+    pub fn from_delta(delta: Pos2<i64>) -> Option<Direction> {
+        match (delta.x(), delta.y()) {
+            (1, 0) => Some(East),
+            (0, -1) => Some(North),
+            (-1, 0) => Some(West),
+            (0, 1) => Some(South),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Direction {
@@ -174,3 +184,67 @@ impl Add<Turn> for Direction {
         self.turn(rhs)
     }
 }
+
+/// one value for each direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DirMap<T>([T; 4]);
+
+impl<T> DirMap<T> {
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(Direction) -> T,
+    {
+        Self([f(East), f(North), f(West), f(South)])
+    }
+
+    #[allow(dead_code)]
+    pub fn get(&self, direction: Direction) -> &T {
+        &self.0[direction as usize]
+    }
+
+    #[allow(dead_code)]
+    pub fn set(&mut self, direction: Direction, value: T) {
+        self.0[direction as usize] = value;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Direction, &T)> {
+        Direction::iter().zip(self.0.iter())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_delta() {
+        for direction in Direction::iter() {
+            assert_eq!(Direction::from_delta(direction.into()), Some(direction));
+        }
+        assert_eq!(Direction::from_delta(Pos2::new(1, 1)), None);
+        assert_eq!(Direction::from_delta(Pos2::new(0, 0)), None);
+        assert_eq!(Direction::from_delta(Pos2::new(2, 0)), None);
+    }
+
+    #[test]
+    fn dir_map() {
+        let mut map = DirMap::default();
+        for (value, direction) in Direction::iter().enumerate() {
+            map.set(direction, value * 10);
+        }
+        assert_eq!(*map.get(East), 0);
+        assert_eq!(*map.get(North), 10);
+        assert_eq!(*map.get(West), 20);
+        assert_eq!(*map.get(South), 30);
+
+        map.set(West, 5);
+        assert_eq!(*map.get(West), 5);
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            [(East, &0), (North, &10), (West, &5), (South, &30)]
+        );
+
+        let arrows = DirMap::from_fn(|direction| direction.arrow());
+        assert_eq!(*arrows.get(South), 'v');
+    }
+}
@@ -1,5 +1,19 @@
 #![allow(dead_code)]
-use std::fmt::Display;
+use super::{direction::Direction, pos2::Pos2};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+    hash::Hash,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum GridError {
+    #[error("Not a decimal digit: {0}")]
+    NotADigit(char),
+    #[error("Grid must be a rectangle")]
+    NotARectangle,
+}
 
 pub fn join<T: Display>(lst: &[T], sep: &str) -> String {
     lst.iter()
@@ -74,3 +88,370 @@ pub fn zip7<A, B, C, D, E, F, G>(
         _ => None,
     }
 }
+
+pub fn group_adjacent<T, F>(slice: &[T], mut same: F) -> Vec<&[T]>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut groups = Vec::new();
+    let mut rest = slice;
+    while !rest.is_empty() {
+        let len = rest
+            .windows(2)
+            .position(|pair| !same(&pair[0], &pair[1]))
+            .map_or(rest.len(), |idx| idx + 1);
+        let (group, tail) = rest.split_at(len);
+        groups.push(group);
+        rest = tail;
+    }
+    groups
+}
+
+pub fn partition_in_place<T, F>(slice: &mut [T], mut predicate: F) -> usize
+where
+    F: FnMut(&T) -> bool,
+{
+    let mut split = 0;
+    for idx in 0..slice.len() {
+        if predicate(&slice[idx]) {
+            slice.swap(split, idx);
+            split += 1;
+        }
+    }
+    split
+}
+
+pub fn minmax<T, I>(iter: I) -> Option<(T, T)>
+where
+    T: Ord + Copy,
+    I: IntoIterator<Item = T>,
+{
+    iter.into_iter().fold(None, |acc, item| match acc {
+        None => Some((item, item)),
+        Some((min, max)) => Some((min.min(item), max.max(item))),
+    })
+}
+
+/// the index of the smallest item, the first one if there are several
+pub fn argmin<T: Ord>(slice: &[T]) -> Option<usize> {
+    slice
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, item)| *item)
+        .map(|(idx, _)| idx)
+}
+
+/// the index of the largest item, the first one if there are several
+pub fn argmax<T: Ord>(slice: &[T]) -> Option<usize> {
+    // max_by_key returns the last of equal items, so search backwards
+    slice
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, item)| *item)
+        .map(|(idx, _)| idx)
+}
+
+pub fn counter<T, I>(iter: I) -> HashMap<T, usize>
+where
+    T: Eq + Hash,
+    I: IntoIterator<Item = T>,
+{
+    let mut counts = HashMap::new();
+    for item in iter {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// all distinct items in the order of their first occurrence
+pub fn dedup_keep_order<T: Eq + Hash + Clone>(items: &[T]) -> Vec<T> {
+    let mut seen = HashSet::new();
+    items
+        .iter()
+        .filter(|item| seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+/// the shortest distance from start to every position of a grid
+/// with the given size. Unreachable positions are None
+pub fn bfs_distances<F>(
+    start: Pos2<usize>,
+    width: usize,
+    height: usize,
+    passable: F,
+) -> Vec<Vec<Option<usize>>>
+where
+    F: Fn(Pos2<usize>) -> bool,
+{
+    let mut distances = vec![vec![None; width]; height];
+    if start.x() >= width || start.y() >= height || !passable(start) {
+        return distances;
+    }
+    distances[start.y()][start.x()] = Some(0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((pos, distance)) = queue.pop_front() {
+        for direction in Direction::iter() {
+            let Some(next) = pos.checked_add_direction(direction) else {
+                continue;
+            };
+            if next.x() < width
+                && next.y() < height
+                && distances[next.y()][next.x()].is_none()
+                && passable(next)
+            {
+                distances[next.y()][next.x()] = Some(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// transposes rows of possibly different lengths. Shorter rows
+/// are padded with fill at their end
+pub fn transpose_ragged<T: Clone>(rows: &[Vec<T>], fill: T) -> Vec<Vec<T>> {
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    (0..width)
+        .map(|x| {
+            rows.iter()
+                .map(|row| row.get(x).cloned().unwrap_or_else(|| fill.clone()))
+                .collect()
+        })
+        .collect()
+}
+
+/// all complete pairs of the slice. A trailing single item is skipped,
+/// see chunk_pairs_remainder
+pub fn chunk_pairs<T: Copy>(slice: &[T]) -> impl Iterator<Item = (T, T)> + '_ {
+    slice.chunks_exact(2).map(|pair| (pair[0], pair[1]))
+}
+
+/// the item left over by chunk_pairs if the slice has an odd length
+pub fn chunk_pairs_remainder<T: Copy>(slice: &[T]) -> Option<T> {
+    slice.chunks_exact(2).remainder().first().copied()
+}
+
+/// all windows of n consecutive items of any iterator, like slice::windows
+/// but with owned items. Panics if n is 0
+pub fn sliding_windows<T, I>(iter: I, n: usize) -> impl Iterator<Item = Vec<T>>
+where
+    T: Clone,
+    I: Iterator<Item = T>,
+{
+    assert!(n > 0, "window size must not be zero");
+    let mut iter = iter;
+    let mut window: VecDeque<T> = iter.by_ref().take(n - 1).collect();
+    iter.map(move |item| {
+        window.push_back(item);
+        if window.len() > n {
+            window.pop_front();
+        }
+        window.iter().cloned().collect()
+    })
+}
+
+fn count_differences<T: PartialEq>(fst: &[T], snd: &[T]) -> usize {
+    fst.iter()
+        .zip(snd.iter())
+        .filter(|(fst, snd)| fst != snd)
+        .count()
+}
+
+/// finds the first reflection line needing exactly the given smudges.
+/// The result is the number of rows above the line. Requiring an exact
+/// match means that a search with one smudge can never return the clean
+/// reflection line
+pub fn find_reflection<T: PartialEq>(rows: &[Vec<T>], smudges: usize) -> Option<usize> {
+    (1..rows.len()).find(|&line| {
+        let above = rows[..line].iter().rev();
+        let below = rows[line..].iter();
+        let mut found = 0;
+        for (fst, snd) in above.zip(below) {
+            found += count_differences(fst, snd);
+            if found > smudges {
+                return false;
+            }
+        }
+        found == smudges
+    })
+}
+
+/// all lines that contain more than just whitespace
+pub fn lines_nonblank(s: &str) -> impl Iterator<Item = &str> {
+    s.lines().filter(|line| !line.trim().is_empty())
+}
+
+/// all lines not starting with the given comment prefix
+pub fn lines_no_comments<'a>(s: &'a str, prefix: &'a str) -> impl Iterator<Item = &'a str> {
+    s.lines().filter(move |line| !line.starts_with(prefix))
+}
+
+/// parses lines of single decimal digits. All lines must have the same length
+pub fn parse_digit_grid(input: &str) -> Result<Vec<Vec<u32>>, GridError> {
+    let grid = input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c.to_digit(10).ok_or(GridError::NotADigit(c)))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if grid.windows(2).any(|rows| rows[0].len() != rows[1].len()) {
+        return Err(GridError::NotARectangle);
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_group_adjacent() {
+        let input = [1, 1, 2, 3, 3, 3, 1];
+        let result = group_adjacent(&input, |a, b| a == b);
+        let expected: Vec<&[i32]> = vec![&[1, 1], &[2], &[3, 3, 3], &[1]];
+        assert_eq!(result, expected);
+
+        let empty: [i32; 0] = [];
+        assert!(group_adjacent(&empty, |a, b| a == b).is_empty());
+    }
+
+    #[test]
+    fn test_partition_in_place() {
+        let mut input = vec![5, 2, 8, 1, 9, 4, 7];
+        let split = partition_in_place(&mut input, |&i| i <= 4);
+        assert_eq!(split, 3);
+        assert!(input[..split].iter().all(|&i| i <= 4));
+        assert!(input[split..].iter().all(|&i| i > 4));
+    }
+
+    #[test]
+    fn test_minmax() {
+        assert_eq!(minmax(Vec::<i32>::new()), None);
+        assert_eq!(minmax([7]), Some((7, 7)));
+        assert_eq!(minmax([3, -2, 8, 0, 8]), Some((-2, 8)));
+    }
+
+    #[test]
+    fn test_argmin_argmax() {
+        let items = [4, 1, 7, 1, 7, 3];
+        assert_eq!(argmin(&items), Some(1));
+        assert_eq!(argmax(&items), Some(2));
+
+        assert_eq!(argmin(&["b", "a"]), Some(1));
+        assert_eq!(argmax::<u8>(&[]), None);
+        assert_eq!(argmin::<u8>(&[]), None);
+    }
+
+    #[test]
+    fn test_transpose_ragged() {
+        let rows = vec![vec![1, 2, 3], vec![4], vec![], vec![5, 6]];
+        let expected = vec![vec![1, 4, 0, 5], vec![2, 0, 0, 6], vec![3, 0, 0, 0]];
+        assert_eq!(transpose_ragged(&rows, 0), expected);
+
+        assert!(transpose_ragged(&Vec::<Vec<char>>::new(), '.').is_empty());
+    }
+
+    #[test]
+    fn test_counter() {
+        let result = counter("abracadabra".chars());
+        let expected = HashMap::from([('a', 5), ('b', 2), ('r', 2), ('c', 1), ('d', 1)]);
+        assert_eq!(result, expected);
+
+        assert!(counter(Vec::<u8>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let grid = ["...", ".#.", "..."];
+        let passable = |pos: Pos2<usize>| grid[pos.y()].as_bytes()[pos.x()] != b'#';
+        let result = bfs_distances(Pos2::new(0, 0), 3, 3, passable);
+        let expected = vec![
+            vec![Some(0), Some(1), Some(2)],
+            vec![Some(1), None, Some(3)],
+            vec![Some(2), Some(3), Some(4)],
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_chunk_pairs() {
+        let even = [1, 2, 3, 4];
+        assert_eq!(chunk_pairs(&even).collect::<Vec<_>>(), [(1, 2), (3, 4)]);
+        assert_eq!(chunk_pairs_remainder(&even), None);
+
+        let odd = [1, 2, 3, 4, 5];
+        assert_eq!(chunk_pairs(&odd).collect::<Vec<_>>(), [(1, 2), (3, 4)]);
+        assert_eq!(chunk_pairs_remainder(&odd), Some(5));
+
+        assert_eq!(chunk_pairs(&[7]).count(), 0);
+        assert_eq!(chunk_pairs_remainder(&[7]), Some(7));
+    }
+
+    #[test]
+    fn test_dedup_keep_order() {
+        let input = [3, 1, 3, 2, 1, 4, 2];
+        assert_eq!(dedup_keep_order(&input), [3, 1, 2, 4]);
+
+        let words = ["b", "a", "b", "b"];
+        assert_eq!(dedup_keep_order(&words), ["b", "a"]);
+
+        assert!(dedup_keep_order::<u8>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_sliding_windows() {
+        let result: Vec<_> = sliding_windows(0..5, 3).collect();
+        assert_eq!(result, [vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4]]);
+
+        assert_eq!(sliding_windows(0..2, 3).count(), 0);
+        assert_eq!(
+            sliding_windows("ab".chars(), 1).collect::<Vec<_>>(),
+            [vec!['a'], vec!['b']]
+        );
+    }
+
+    #[test]
+    fn test_find_reflection() {
+        let rows = vec![
+            vec![true, false],
+            vec![false, false],
+            vec![false, false],
+            vec![true, false],
+            vec![true, true],
+        ];
+        assert_eq!(find_reflection(&rows, 0), Some(2));
+        assert_eq!(find_reflection(&rows, 1), Some(1));
+        assert_eq!(find_reflection(&rows[..1], 0), None);
+
+        let sequence: Vec<_> = "abccb".chars().map(|c| vec![c]).collect();
+        assert_eq!(find_reflection(&sequence, 0), Some(3));
+    }
+
+    #[test]
+    fn test_lines_filters() {
+        let input = "a\n\n# comment\n  \nb\n//c\n";
+        assert_eq!(
+            lines_nonblank(input).collect::<Vec<_>>(),
+            ["a", "# comment", "b", "//c"]
+        );
+        assert_eq!(
+            lines_no_comments(input, "#").collect::<Vec<_>>(),
+            ["a", "", "  ", "b", "//c"]
+        );
+        assert_eq!(lines_no_comments(input, "//").count(), 5);
+    }
+
+    #[test]
+    fn test_parse_digit_grid() {
+        let result = parse_digit_grid("123\n405\n");
+        assert_eq!(result, Ok(vec![vec![1, 2, 3], vec![4, 0, 5]]));
+
+        assert_eq!(parse_digit_grid("12\n3x"), Err(GridError::NotADigit('x')));
+        assert_eq!(parse_digit_grid("12\n345"), Err(GridError::NotARectangle));
+        assert_eq!(parse_digit_grid(""), Ok(vec![]));
+    }
+}
@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+use super::helper::partition_in_place;
 use num_traits::{Euclid, Num};
 use rand::rngs::ThreadRng;
 use rand::Rng;
@@ -76,6 +77,76 @@ where
     }
 }
 
+/// combines the congruences x = r1 (mod m1) and x = r2 (mod m2)
+/// into one congruence modulo lcm(m1, m2). The moduli need not be coprime.
+/// Returns None if there is no common solution
+pub fn chinese_remainder<T>((r1, m1): (T, T), (r2, m2): (T, T)) -> Option<(T, T)>
+where
+    T: Num + Ord + Euclid + Copy,
+{
+    let g = gcd(m1, m2)?;
+    let diff = r2 - r1;
+    if !(diff % g).is_zero() {
+        return None;
+    }
+    let m2g = m2 / g;
+    let inv = modulus_inv(m1 / g, m2g)?;
+    let k = (diff / g * inv).rem_euclid(&m2g);
+    let modulo = m1 * m2g;
+    Some(((r1 + m1 * k).rem_euclid(&modulo), modulo))
+}
+
+pub fn triangular(n: u64) -> u64 {
+    n * (n + 1) / 2
+}
+
+pub fn sum_range(from: u64, to: u64) -> u64 {
+    if from > to {
+        0
+    } else {
+        (from + to) * (to - from + 1) / 2
+    }
+}
+
+/// None if the result does not fit into an u64 (n > 20)
+pub fn factorial(n: u64) -> Option<u64> {
+    (2..=n).try_fold(1u64, |acc, i| acc.checked_mul(i))
+}
+
+/// the number of ways to choose k out of n items. It is computed step by
+/// step, so it works as long as the result fits into an u64. None otherwise
+pub fn binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    // every intermediate result is a binomial itself, so it is exact
+    let result = (0..k).try_fold(1u128, |acc, i| {
+        Some(acc.checked_mul((n - i) as u128)? / (i + 1) as u128)
+    })?;
+    u64::try_from(result).ok()
+}
+
+/// the largest integer whose square is not bigger than n
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n / 2 + 1;
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+pub fn is_perfect_square(n: u64) -> bool {
+    let root = isqrt(n);
+    root * root == n
+}
+
 fn quick_select<T: Ord + Copy>(lst: &mut [T], index: usize, mut rng: ThreadRng) -> T {
     match lst.len() {
         0 => unreachable!(),
@@ -90,7 +161,7 @@ fn quick_select<T: Ord + Copy>(lst: &mut [T], index: usize, mut rng: ThreadRng)
         },
         n => {
             let pivot = lst[rng.gen_range(0..n)];
-            let lesser = lst.iter_mut().partition_in_place(|&i| i <= pivot);
+            let lesser = partition_in_place(lst, |&i| i <= pivot);
             if lesser > index {
                 quick_select(&mut lst[..lesser], index, rng)
             } else {
@@ -137,10 +208,66 @@ mod tests {
         assert_eq!(inv, Some(7));
     }
 
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(chinese_remainder((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(chinese_remainder((0, 4), (2, 6)), Some((8, 12)));
+        assert_eq!(chinese_remainder((1, 4), (2, 6)), None);
+    }
+
     #[test]
     fn test_median() {
         let mut input = vec![9, 1, 0, 2, 3, 4, 6, 8, 7, 10, 5];
         let expected = 5;
         assert_eq!(median(&mut input), expected);
     }
+
+    #[test]
+    fn test_triangular() {
+        assert_eq!(triangular(0), 0);
+        assert_eq!(triangular(10), 55);
+    }
+
+    #[test]
+    fn test_sum_range() {
+        assert_eq!(sum_range(3, 7), 25);
+        assert_eq!(sum_range(0, 10), triangular(10));
+        assert_eq!(sum_range(5, 5), 5);
+        assert_eq!(sum_range(7, 3), 0);
+    }
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial(0), Some(1));
+        assert_eq!(factorial(5), Some(120));
+        assert_eq!(factorial(20), Some(2_432_902_008_176_640_000));
+        assert_eq!(factorial(21), None);
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(5, 2), Some(10));
+        assert_eq!(binomial(5, 0), Some(1));
+        assert_eq!(binomial(5, 5), Some(1));
+        assert_eq!(binomial(5, 7), Some(0));
+        assert_eq!(binomial(60, 30), Some(118_264_581_564_861_424));
+        assert_eq!(binomial(67, 33), Some(14_226_520_737_620_288_370));
+        assert_eq!(binomial(68, 34), None);
+        assert_eq!(binomial(u64::MAX, 3), None);
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(24), 4);
+        assert_eq!(isqrt(25), 5);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+
+        assert!(is_perfect_square(25));
+        assert!(!is_perfect_square(24));
+        assert!(is_perfect_square(0));
+        assert!(is_perfect_square(1 << 62));
+        assert!(!is_perfect_square((1 << 62) + 1));
+    }
 }
@@ -39,6 +39,18 @@ where
     pub fn det(&self) -> T {
         self.0[0].x() * self.0[1].y() - self.0[0].y() * self.0[1].x()
     }
+
+    /// solves self * x = rhs using Cramer's rule. Returns None if the
+    /// matrix is singular. Integer types will get a truncated result
+    pub fn solve(&self, rhs: Pos2<T>) -> Option<Pos2<T>> {
+        let det = self.det();
+        if det.is_zero() {
+            return None;
+        }
+        let x = Matrix2::from_col_vectors(rhs, self.0[1]).det();
+        let y = Matrix2::from_col_vectors(self.0[0], rhs).det();
+        Some(Pos2::new(x / det, y / det))
+    }
 }
 
 impl<T> Matrix2<T>
@@ -148,3 +160,23 @@ where
         Self::from_col_vectors(self.0[0] + rhs.0[0], self.0[1] + rhs.0[1])
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solve() {
+        // 2x + y = 5, x - y = 1
+        let matrix = Matrix2::from_row_vectors(Pos2::new(2.0, 1.0), Pos2::new(1.0, -1.0));
+        let result = matrix.solve(Pos2::new(5.0, 1.0));
+        assert_eq!(result, Some(Pos2::new(2.0, 1.0)));
+        assert_eq!(matrix * Pos2::new(2.0, 1.0), Pos2::new(5.0, 1.0));
+
+        let matrix = Matrix2::from_row_vectors(Pos2::new(3, 2), Pos2::new(1, 4));
+        assert_eq!(matrix.solve(Pos2::new(-1, 13)), Some(Pos2::new(-3, 4)));
+
+        let singular = Matrix2::from_row_vectors(Pos2::new(1, 2), Pos2::new(2, 4));
+        assert_eq!(singular.solve(Pos2::new(3, 6)), None);
+    }
+}
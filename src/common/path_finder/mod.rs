@@ -23,6 +23,11 @@ pub trait PathFinder {
     fn init_skipper(&self) -> Self::Skipper {
         Self::Skipper::init()
     }
+
+    /// called for every item taken from the queue, e.g. to report progress
+    #[inline]
+    #[allow(unused_variables)]
+    fn on_expand(&self, item: &Self::Item) {}
 }
 
 pub fn find_best_path<P: PathFinder>(path_finder: P) -> Option<P::Item> {
@@ -32,6 +37,7 @@ pub fn find_best_path<P: PathFinder>(path_finder: P) -> Option<P::Item> {
     queue.push(path_finder.get_start_item());
 
     while let Some(item) = queue.pop() {
+        path_finder.on_expand(&item);
         if path_finder.is_finished(&item) && !skipper.skip_when_finished(&item) {
             return Some(item);
         }
@@ -47,3 +53,94 @@ pub fn find_best_path<P: PathFinder>(path_finder: P) -> Option<P::Item> {
 
     None
 }
+
+/// like find_best_path, but keeps searching after the first finished item
+/// and drops every item whose cost cannot beat the best one found so far.
+/// The cost of an item must never be lower than the cost of its predecessor
+pub fn find_best_path_bounded<P, F, C>(path_finder: P, cost: F) -> Option<P::Item>
+where
+    P: PathFinder,
+    F: Fn(&P::Item) -> C,
+    C: Ord,
+{
+    let mut skipper = path_finder.init_skipper();
+
+    let mut queue = P::Queue::create();
+    queue.push(path_finder.get_start_item());
+
+    let mut best: Option<(C, P::Item)> = None;
+    let beats_best = |best: &Option<(C, P::Item)>, item_cost: &C| {
+        best.as_ref()
+            .is_none_or(|(best_cost, _)| item_cost < best_cost)
+    };
+
+    while let Some(item) = queue.pop() {
+        let item_cost = cost(&item);
+        if !beats_best(&best, &item_cost) {
+            continue;
+        }
+
+        path_finder.on_expand(&item);
+        if path_finder.is_finished(&item) && !skipper.skip_when_finished(&item) {
+            best = Some((item_cost, item));
+            continue;
+        }
+
+        if skipper.skip_item(&item) {
+            continue;
+        }
+
+        for next_item in path_finder.get_next_states(&item) {
+            if beats_best(&best, &cost(&next_item)) {
+                queue.push(next_item)
+            }
+        }
+    }
+
+    best.map(|(_, item)| item)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{item_skipper::NoneSkipper, *};
+    use std::cell::Cell;
+
+    struct CountDown<'a> {
+        expanded: &'a Cell<usize>,
+    }
+
+    impl PathFinder for CountDown<'_> {
+        type Item = u32;
+        type Queue = Vec<u32>;
+        type Skipper = NoneSkipper<u32>;
+
+        fn get_start_item(&self) -> Self::Item {
+            3
+        }
+
+        fn is_finished(&self, item: &Self::Item) -> bool {
+            *item == 0
+        }
+
+        fn get_next_states<'a>(
+            &'a self,
+            item: &'a Self::Item,
+        ) -> impl Iterator<Item = Self::Item> + 'a {
+            item.checked_sub(1).into_iter()
+        }
+
+        fn on_expand(&self, _item: &Self::Item) {
+            self.expanded.set(self.expanded.get() + 1);
+        }
+    }
+
+    #[test]
+    fn on_expand() {
+        let expanded = Cell::new(0);
+        let finder = CountDown {
+            expanded: &expanded,
+        };
+        assert_eq!(find_best_path(finder), Some(0));
+        assert_eq!(expanded.get(), 4);
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::direction::Direction;
 use super::pos3::Pos3;
+use super::turn::Turn;
 use super::{abs::Abs, math::gcd};
 use num_traits::{CheckedAdd, CheckedSub, Float, Num, NumCast, One, Signed, Zero};
 use std::fmt;
@@ -28,6 +29,12 @@ impl<T> Pos2<T> {
     pub fn get_y(&self) -> &T {
         &self.y
     }
+
+    /// applies f to each component
+    #[inline]
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Pos2<U> {
+        Pos2::new(f(self.x), f(self.y))
+    }
 }
 
 impl<T> From<[T; 2]> for Pos2<T> {
@@ -113,6 +120,14 @@ where
             self.x * col1.y + self.y * col2.y,
         )
     }
+
+    pub fn component_sum(self) -> T {
+        self.x + self.y
+    }
+
+    pub fn product(self) -> T {
+        self.x * self.y
+    }
 }
 
 impl<T> Pos2<T>
@@ -173,6 +188,18 @@ where
     }
 }
 
+impl<T> Pos2<T>
+where
+    T: NumCast + Copy,
+{
+    /// converts the components to another number type,
+    /// None if one of them does not fit. Floats are truncated toward
+    /// zero when cast to an integer type, so 2.5 becomes 2 and -2.5 becomes -2
+    pub fn cast<U: NumCast>(self) -> Option<Pos2<U>> {
+        Some(Pos2::new(U::from(self.x)?, U::from(self.y)?))
+    }
+}
+
 impl<T> Pos2<T>
 where
     T: Ord + Copy,
@@ -366,6 +393,36 @@ where
     }
 }
 
+impl<T> Pos2<T>
+where
+    T: Signed + Copy,
+{
+    /// rotates by 90 degrees so that East becomes North
+    /// (with y pointing down as in the grids)
+    pub fn rotate_90_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// rotates by 90 degrees so that East becomes South
+    /// (with y pointing down as in the grids)
+    pub fn rotate_90_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn rotate(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Forward => self,
+            Turn::Left => self.rotate_90_left(),
+            Turn::Back => -self,
+            Turn::Right => self.rotate_90_right(),
+        }
+    }
+
+    pub fn rotate_around(self, center: Pos2<T>, turn: Turn) -> Self {
+        (self - center).rotate(turn) + center
+    }
+}
+
 impl Pos2<usize> {
     pub fn matrix_get<'a, A>(&self, mat: &'a [Vec<A>]) -> &'a A {
         &mat[self.y()][self.x()]
@@ -394,3 +451,148 @@ impl Pos2<usize> {
             .and_then(|row| row.get_mut(self.x()).map(|val| *val = new_value));
     }
 }
+
+impl Pos2<i64> {
+    pub fn line_to(self, other: Pos2<i64>) -> LineIterator {
+        LineIterator::new(self, other)
+    }
+}
+
+pub struct LineIterator {
+    current: Option<Pos2<i64>>,
+    target: Pos2<i64>,
+    delta: Pos2<i64>,
+    step: Pos2<i64>,
+    error: i64,
+}
+
+impl LineIterator {
+    fn new(start: Pos2<i64>, target: Pos2<i64>) -> Self {
+        let delta = Pos2::new((target.x - start.x).abs(), -(target.y - start.y).abs());
+        let step = Pos2::new((target.x - start.x).signum(), (target.y - start.y).signum());
+        Self {
+            current: Some(start),
+            target,
+            delta,
+            step,
+            error: delta.x + delta.y,
+        }
+    }
+}
+
+impl Iterator for LineIterator {
+    type Item = Pos2<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        if current == self.target {
+            self.current = None;
+            return Some(current);
+        }
+
+        let mut next = current;
+        let double_error = 2 * self.error;
+        if double_error >= self.delta.y {
+            self.error += self.delta.y;
+            next.x += self.step.x;
+        }
+        if double_error <= self.delta.x {
+            self.error += self.delta.x;
+            next.y += self.step.y;
+        }
+        self.current = Some(next);
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn map() {
+        let pos = Pos2::new(1, 2);
+        assert_eq!(pos.map(|c| c * 2), Pos2::new(2, 4));
+        assert_eq!(pos.map(|c| c as f64 / 2.0), Pos2::new(0.5, 1.0));
+        assert_eq!(
+            pos.map(|c| c.to_string()),
+            Pos2::new("1".to_owned(), "2".to_owned())
+        );
+    }
+
+    #[test]
+    fn line_horizontal() {
+        let result = Pos2::new(1, 2).line_to(Pos2::new(4, 2)).collect::<Vec<_>>();
+        let expected = vec![
+            Pos2::new(1, 2),
+            Pos2::new(2, 2),
+            Pos2::new(3, 2),
+            Pos2::new(4, 2),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn line_vertical() {
+        let result = Pos2::new(0, 1)
+            .line_to(Pos2::new(0, -2))
+            .collect::<Vec<_>>();
+        let expected = vec![
+            Pos2::new(0, 1),
+            Pos2::new(0, 0),
+            Pos2::new(0, -1),
+            Pos2::new(0, -2),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn line_diagonal() {
+        let result = Pos2::new(3, 0).line_to(Pos2::new(0, 3)).collect::<Vec<_>>();
+        let expected = vec![
+            Pos2::new(3, 0),
+            Pos2::new(2, 1),
+            Pos2::new(1, 2),
+            Pos2::new(0, 3),
+        ];
+        assert_eq!(result, expected);
+
+        let result = Pos2::new(5, 5).line_to(Pos2::new(5, 5)).collect::<Vec<_>>();
+        assert_eq!(result, vec![Pos2::new(5, 5)]);
+    }
+
+    #[test]
+    fn rotate_around() {
+        let center = Pos2::new(2, 3);
+        let pos = Pos2::new(4, 3);
+        assert_eq!(pos.rotate_around(center, Turn::Left), Pos2::new(2, 1));
+        assert_eq!(pos.rotate_around(center, Turn::Right), Pos2::new(2, 5));
+        assert_eq!(pos.rotate_around(center, Turn::Back), Pos2::new(0, 3));
+        assert_eq!(pos.rotate_around(center, Turn::Forward), pos);
+        assert_eq!(center.rotate_around(center, Turn::Left), center);
+    }
+
+    #[test]
+    fn cast() {
+        let pos = Pos2::<i64>::new(-3, 7);
+        assert_eq!(pos.cast::<f64>(), Some(Pos2::new(-3.0, 7.0)));
+        assert_eq!(pos.cast::<u8>(), None);
+        assert_eq!(Pos2::new(300i64, 1).cast::<u8>(), None);
+        assert_eq!(Pos2::new(2.5f64, 1.0).cast::<i32>(), Some(Pos2::new(2, 1)));
+        assert_eq!(
+            Pos2::new(-2.5f64, 0.9).cast::<i32>(),
+            Some(Pos2::new(-2, 0))
+        );
+        assert_eq!(Pos2::new(-0.5f64, 0.0).cast::<u8>(), Some(Pos2::new(0, 0)));
+        assert_eq!(Pos2::new(f64::NAN, 0.0).cast::<i32>(), None);
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let pos = Pos2::new(3, 4);
+        assert_eq!(pos.component_sum(), 7);
+        assert_eq!(pos.product(), 12);
+        assert_eq!(Pos2::new(-2.5, 2.0).component_sum(), -0.5);
+        assert_eq!(Pos2::new(-2.5, 2.0).product(), -5.0);
+    }
+}
@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use num_traits::{Num, PrimInt, Signed, Zero};
+use num_traits::{Num, NumCast, PrimInt, Signed, Zero};
 use std::fmt;
 use std::ops::{Add, AddAssign, Div, Index, Mul, Neg, Sub};
 
@@ -33,6 +33,12 @@ impl<T> Pos3<T> {
     pub fn get_z(&self) -> &T {
         &self.z
     }
+
+    /// applies f to each component
+    #[inline]
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Pos3<U> {
+        Pos3::new(f(self.x), f(self.y), f(self.z))
+    }
 }
 
 impl<T: Signed + PrimInt> Pos3<T> {
@@ -41,6 +47,22 @@ impl<T: Signed + PrimInt> Pos3<T> {
     }
 }
 
+impl<T: PrimInt> Pos3<T> {
+    /// the six neighbors sharing a face with this position.
+    /// Neighbors that would overflow the type are left out
+    pub fn neighbors6(self) -> impl Iterator<Item = Pos3<T>> {
+        (0..3).flat_map(move |idx| {
+            [
+                self[idx].checked_sub(&T::one()),
+                self[idx].checked_add(&T::one()),
+            ]
+            .into_iter()
+            .flatten()
+            .map(move |value| self.set(idx, value))
+        })
+    }
+}
+
 impl<T: Signed> Pos3<T> {
     pub fn signum(&self) -> Pos3<T> {
         Pos3::new(self.x.signum(), self.y.signum(), self.z.signum())
@@ -72,6 +94,18 @@ impl<T: Copy> Pos3<T> {
     }
 }
 
+impl<T: NumCast + Copy> Pos3<T> {
+    /// converts the components to another number type,
+    /// None if one of them does not fit
+    pub fn cast<U: NumCast>(self) -> Option<Pos3<U>> {
+        Some(Pos3::new(
+            U::from(self.x)?,
+            U::from(self.y)?,
+            U::from(self.z)?,
+        ))
+    }
+}
+
 impl<T: Copy> From<[T; 3]> for Pos3<T> {
     fn from(value: [T; 3]) -> Self {
         Pos3::new(value[0], value[1], value[2])
@@ -371,3 +405,47 @@ impl<T: Copy> Iterator for PosIterator<T> {
         Some(self.pos[idx])
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn map() {
+        let pos = Pos3::new(1i32, 2, -3);
+        assert_eq!(pos.map(|c| c * 2), Pos3::new(2, 4, -6));
+        assert_eq!(pos.map(|c| c.unsigned_abs()), Pos3::new(1u32, 2, 3));
+    }
+
+    #[test]
+    fn neighbors6() {
+        let neighbors: Vec<_> = Pos3::new(1, 2, 3).neighbors6().collect();
+        let expected = vec![
+            Pos3::new(0, 2, 3),
+            Pos3::new(2, 2, 3),
+            Pos3::new(1, 1, 3),
+            Pos3::new(1, 3, 3),
+            Pos3::new(1, 2, 2),
+            Pos3::new(1, 2, 4),
+        ];
+        assert_eq!(neighbors, expected);
+
+        let neighbors: Vec<_> = Pos3::<usize>::new(0, 5, 0).neighbors6().collect();
+        let expected = vec![
+            Pos3::new(1, 5, 0),
+            Pos3::new(0, 4, 0),
+            Pos3::new(0, 6, 0),
+            Pos3::new(0, 5, 1),
+        ];
+        assert_eq!(neighbors, expected);
+    }
+
+    #[test]
+    fn cast() {
+        let pos = Pos3::<i64>::new(19, -13, 30);
+        let float = pos.cast::<f64>();
+        assert_eq!(float, Some(Pos3::new(19.0, -13.0, 30.0)));
+        assert_eq!(float.and_then(|float| float.cast::<i64>()), Some(pos));
+        assert_eq!(pos.cast::<u32>(), None);
+    }
+}
@@ -1,5 +1,12 @@
 use super::direction::Direction;
 use std::{fmt::Display, ops::Add};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TurnError {
+    #[error("Not a valid turn: {0}")]
+    NotATurn(char),
+}
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -13,6 +20,10 @@ pub enum Turn {
 use Turn::*;
 
 impl Turn {
+    pub fn iter() -> impl Iterator<Item = Turn> {
+        [Left, Right, Forward, Back].into_iter()
+    }
+
     pub fn from_i64(value: i64) -> Turn {
         match value % 4 {
             0 => Turn::Forward,
@@ -58,6 +69,29 @@ impl Turn {
             Forward => Forward,
         }
     }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Left => 'L',
+            Back => 'B',
+            Right => 'R',
+            Forward => 'F',
+        }
+    }
+}
+
+impl TryFrom<char> for Turn {
+    type Error = TurnError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Left),
+            'R' => Ok(Right),
+            'S' | 'F' => Ok(Forward),
+            'B' => Ok(Back),
+            _ => Err(TurnError::NotATurn(value)),
+        }
+    }
 }
 
 impl Display for Turn {
@@ -139,3 +173,35 @@ impl Add<&Direction> for &Turn {
         rhs.turn(*self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_turn() {
+        assert_eq!(Turn::try_from('L').ok(), Some(Left));
+        assert_eq!(Turn::try_from('R').ok(), Some(Right));
+        assert_eq!(Turn::try_from('S').ok(), Some(Forward));
+        assert_eq!(Turn::try_from('F').ok(), Some(Forward));
+        assert_eq!(Turn::try_from('B').ok(), Some(Back));
+        assert!(matches!(Turn::try_from('X'), Err(TurnError::NotATurn('X'))));
+    }
+
+    #[test]
+    fn turn_to_char() {
+        for turn in Turn::iter() {
+            assert_eq!(Turn::try_from(turn.to_char()).ok(), Some(turn));
+        }
+    }
+
+    #[test]
+    fn iter_and_display() {
+        assert_eq!(
+            Turn::iter().collect::<Vec<_>>(),
+            [Left, Right, Forward, Back]
+        );
+        let names: Vec<_> = Turn::iter().map(|turn| turn.to_string()).collect();
+        assert_eq!(names, ["Left", "Right", "Forward", "Back"]);
+    }
+}
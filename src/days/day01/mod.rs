@@ -1,4 +1,4 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 
 const DAY_NUMBER: DayType = 1;
 
@@ -18,6 +18,12 @@ impl DayTrait for Day {
         let result: u32 = day_impl::get_worded_digits(input).sum();
         Ok(result.into())
     }
+
+    /// the calibration values are all there is to parse
+    fn parse_only(&self, input: &str) -> UnitResult {
+        day_impl::get_digits(input).for_each(drop);
+        Ok(())
+    }
 }
 
 mod day_impl {
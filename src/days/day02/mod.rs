@@ -1,6 +1,10 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
-use std::{num, ops::Add, str::FromStr};
+use std::{
+    num,
+    ops::{Add, Sub},
+    str::FromStr,
+};
 
 const DAY_NUMBER: DayType = 2;
 const SUPPOSED_CUBES: Set = Set::new(12, 13, 14);
@@ -31,6 +35,11 @@ impl DayTrait for Day {
 
         Ok(result.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Vec<Game> = input.lines().map(|line| line.parse()).try_collect()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -77,6 +86,12 @@ impl Set {
         )
     }
 
+    /// multiplies every color by the given factor
+    #[allow(dead_code)]
+    pub fn scale(self, factor: IntType) -> Self {
+        Self::new(self.red * factor, self.green * factor, self.blue * factor)
+    }
+
     fn add_red(mut self, red: IntType) -> Self {
         self.red += red;
         self
@@ -93,6 +108,32 @@ impl Set {
     }
 }
 
+impl Add for Set {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.red + rhs.red,
+            self.green + rhs.green,
+            self.blue + rhs.blue,
+        )
+    }
+}
+
+impl Sub for Set {
+    type Output = Self;
+
+    /// removes the cubes of rhs. As there are no negative amounts
+    /// of cubes every color stops at zero
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.red.saturating_sub(rhs.red),
+            self.green.saturating_sub(rhs.green),
+            self.blue.saturating_sub(rhs.blue),
+        )
+    }
+}
+
 impl FromStr for Set {
     type Err = DayError;
 
@@ -228,4 +269,11 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn set_arithmetic() {
+        assert_eq!(Set::new(1, 2, 3) + Set::new(4, 5, 6), Set::new(5, 7, 9));
+        assert_eq!(Set::new(5, 2, 3) - Set::new(4, 5, 3), Set::new(1, 0, 0));
+        assert_eq!(Set::new(1, 0, 3).scale(4), Set::new(4, 0, 12));
+    }
 }
@@ -1,4 +1,4 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use crate::common::pos2::Pos2;
 use itertools::Itertools;
 use std::{num, str::FromStr};
@@ -20,9 +20,14 @@ impl DayTrait for Day {
 
     fn part2(&self, input: &str) -> RResult {
         let schema: Schema = input.parse()?;
-        let result: i64 = schema.get_gears().into_iter().sum();
+        let result: i64 = schema.get_gears('*').into_iter().sum();
         Ok(result.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Schema = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -89,41 +94,53 @@ impl FromStr for Schema {
 }
 
 impl Schema {
-    fn find_symbol(&self, start: &Pos2<usize>, len: usize) -> bool {
+    /// is pos next to (or on) the number starting at start with len digits?
+    /// Numbers in the first row or column have no neighbors above or to the left
+    fn touches(start: &Pos2<usize>, len: usize, pos: &Pos2<usize>) -> bool {
+        (start.y().saturating_sub(1)..=start.y() + 1).contains(&pos.y())
+            && (start.x().saturating_sub(1)..=start.x() + len).contains(&pos.x())
+    }
+
+    fn find_symbol<F>(&self, start: &Pos2<usize>, len: usize, is_symbol: &F) -> bool
+    where
+        F: Fn(char) -> bool,
+    {
         self.information.iter().any(|info| match info {
-            Information::Symbol(_, pos) => {
-                (start.y().saturating_sub(1)..=start.y() + 1).contains(&pos.y())
-                    && (start.x().saturating_sub(1)..=start.x() + len).contains(&pos.x())
+            Information::Symbol(symbol, pos) => {
+                is_symbol(*symbol) && Schema::touches(start, len, pos)
             }
             Information::Number(_, _, _) => false,
         })
     }
 
-    pub fn filter_adjacent(&self) -> Vec<i64> {
+    /// all numbers that are adjacent to at least one symbol
+    /// accepted by is_symbol
+    pub fn parts_adjacent_to<F>(&self, is_symbol: F) -> Vec<i64>
+    where
+        F: Fn(char) -> bool,
+    {
         self.information
             .iter()
             .filter_map(|info| match info {
                 Information::Symbol(_, _) => None,
                 Information::Number(num, start, len) => {
-                    self.find_symbol(start, *len).then_some(*num)
+                    self.find_symbol(start, *len, &is_symbol).then_some(*num)
                 }
             })
             .collect_vec()
     }
 
+    pub fn filter_adjacent(&self) -> Vec<i64> {
+        self.parts_adjacent_to(|_| true)
+    }
+
     pub fn check_gear(&self, pos: &Pos2<usize>) -> Option<i64> {
         let gear = self
             .information
             .iter()
             .filter_map(|info| match info {
                 Information::Number(num, start, len) => {
-                    if (start.y().saturating_sub(1)..=start.y() + 1).contains(&pos.y())
-                        && (start.x().saturating_sub(1)..=start.x() + len).contains(&pos.x())
-                    {
-                        Some(*num)
-                    } else {
-                        None
-                    }
+                    Schema::touches(start, *len, pos).then_some(*num)
                 }
                 Information::Symbol(_, _) => None,
             })
@@ -136,11 +153,12 @@ impl Schema {
         }
     }
 
-    pub fn get_gears(&self) -> Vec<i64> {
+    /// a gear is any given symbol with exactly two adjacent numbers
+    pub fn get_gears(&self, gear: char) -> Vec<i64> {
         self.information
             .iter()
             .filter_map(|info| match info {
-                Information::Symbol('*', pos) => self.check_gear(pos),
+                Information::Symbol(symbol, pos) if *symbol == gear => self.check_gear(pos),
                 _ => None,
             })
             .collect_vec()
@@ -210,7 +228,35 @@ mod test {
         let input = read_string(day.get_day_number(), "example01.txt")?;
         let schema: Schema = input.parse()?;
         let expected = [16345, 451490];
-        assert_eq!(schema.get_gears(), expected);
+        assert_eq!(schema.get_gears('*'), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn restricted_symbols() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let schema: Schema = input.parse()?;
+        assert_eq!(schema.parts_adjacent_to(|symbol| symbol == '$'), [664]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn numbers_at_boundary() -> UnitResult {
+        let schema: Schema = "7#..\n....\n..12".parse()?;
+        assert_eq!(schema.filter_adjacent(), [7]);
+
+        let schema: Schema = "3...\n*..5\n...$".parse()?;
+        assert_eq!(schema.filter_adjacent(), [3, 5]);
+
+        let schema: Schema = "12*34\n.....".parse()?;
+        assert_eq!(schema.filter_adjacent(), [12, 34]);
+        assert_eq!(schema.get_gears('*'), [408]);
+
+        let schema: Schema = "42..\n..+.".parse()?;
+        assert_eq!(schema.filter_adjacent(), [42]);
 
         Ok(())
     }
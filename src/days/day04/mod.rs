@@ -1,4 +1,4 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use std::{num, str::FromStr};
 
@@ -20,6 +20,11 @@ impl DayTrait for Day {
         let deck: Deck = input.parse()?;
         Ok(deck.collect_winning().into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Deck = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -75,6 +80,17 @@ impl Card {
             .count()
     }
 
+    /// the winning numbers found in the hand, in the order
+    /// they appear in the winning numbers
+    #[allow(dead_code)]
+    pub fn matching_numbers(&self) -> Vec<u32> {
+        self.winning
+            .iter()
+            .filter(|winning| self.hand.contains(winning))
+            .copied()
+            .collect()
+    }
+
     #[inline]
     pub fn winning_value(&self) -> u32 {
         let winning = self.count_winning_numbers() as u32;
@@ -170,6 +186,7 @@ mod test {
         };
         assert_eq!(card, expected);
         assert_eq!(card.winning_value(), 8);
+        assert_eq!(card.matching_numbers(), vec![48, 83, 86, 17]);
 
         Ok(())
     }
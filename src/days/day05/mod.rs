@@ -1,4 +1,6 @@
-use super::{DayTrait, DayType, RResult};
+use crate::common::helper::chunk_pairs;
+
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use std::{num, ops::Range, str::FromStr};
 
@@ -22,6 +24,11 @@ impl DayTrait for Day {
         let locations = almanach.range_location();
         Ok(locations.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Almanach = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -32,6 +39,8 @@ enum DayError {
     ParseIntError(#[from] num::ParseIntError),
     #[error("No Mapping was given to us")]
     NoMappingGiven,
+    #[error("Source ranges overlap: {0:?} and {1:?}")]
+    OverlappingRanges(Range<u64>, Range<u64>),
 }
 
 fn range_overlaps(first: &Range<u64>, second: &Range<u64>) -> bool {
@@ -119,6 +128,24 @@ impl RangeMappings {
             None
         }
     }
+
+    pub fn map_source_range(&self, source: &Range<u64>) -> (Option<Range<u64>>, Vec<Range<u64>>) {
+        if !range_overlaps(&self.source, source) {
+            return (None, vec![source.clone()]);
+        }
+        let start = source.start.max(self.source.start);
+        let end = source.end.min(self.source.end);
+        let mut rest = vec![];
+        if source.start < start {
+            rest.push(source.start..start);
+        }
+        if end < source.end {
+            rest.push(end..source.end);
+        }
+        let offset = self.dest.start;
+        let mapped = start - self.source.start + offset..end - self.source.start + offset;
+        (Some(mapped), rest)
+    }
 }
 
 struct Mapping {
@@ -126,7 +153,19 @@ struct Mapping {
 }
 
 impl Mapping {
-    pub fn new(ranges: Vec<RangeMappings>) -> Self {
+    pub fn new(ranges: Vec<RangeMappings>) -> Result<Self, DayError> {
+        if let Some((first, second)) = ranges
+            .iter()
+            .sorted_by_key(|range| range.source.start)
+            .tuple_windows()
+            .find(|(first, second)| range_overlaps(&first.source, &second.source))
+        {
+            return Err(DayError::OverlappingRanges(
+                first.source.clone(),
+                second.source.clone(),
+            ));
+        }
+
         let (last, mut ranges) =
             ranges
                 .into_iter()
@@ -140,7 +179,7 @@ impl Mapping {
                     (end, ranges)
                 });
         ranges.push(RangeMappings::new_equal(last, u64::MAX));
-        Self { ranges }
+        Ok(Self { ranges })
     }
 
     pub fn gather<'a>(iter: &mut impl Iterator<Item = &'a str>) -> Result<Option<Self>, DayError> {
@@ -160,7 +199,7 @@ impl Mapping {
             .map(|line| line.parse())
             .try_collect()?;
 
-        Ok(Some(Self::new(mappings)))
+        Ok(Some(Self::new(mappings)?))
     }
 
     pub fn convert(&self, source: u64) -> u64 {
@@ -177,6 +216,27 @@ impl Mapping {
             .filter_map(|range| range.possible_dest_split(dest))
             .collect_vec()
     }
+
+    pub fn map_range(&self, input: &Range<u64>) -> Vec<Range<u64>> {
+        let mut mapped = vec![];
+        let mut unmapped = vec![input.clone()];
+        for range in self
+            .ranges
+            .iter()
+            .filter(|range| range.source != range.dest)
+        {
+            let mut rest = vec![];
+            for part in unmapped {
+                let (part_mapped, mut part_rest) = range.map_source_range(&part);
+                mapped.extend(part_mapped);
+                rest.append(&mut part_rest);
+            }
+            unmapped = rest;
+        }
+        mapped.append(&mut unmapped);
+        mapped.sort_by_key(|range| range.start);
+        mapped
+    }
 }
 
 struct Almanach {
@@ -207,6 +267,31 @@ impl Almanach {
         })
     }
 
+    /// all locations the seed ranges map to, sorted and merged
+    #[allow(dead_code)]
+    pub fn location_ranges(&self) -> Vec<Range<u64>> {
+        let seeds = chunk_pairs(&self.seeds)
+            .map(|(start, len)| start..start + len)
+            .collect_vec();
+        self.mappings
+            .iter()
+            .fold(seeds, |ranges, map| {
+                ranges
+                    .iter()
+                    .flat_map(|range| map.map_range(range))
+                    .collect_vec()
+            })
+            .into_iter()
+            .sorted_by_key(|range| range.start)
+            .fold(vec![], |mut merged: Vec<Range<u64>>, range| {
+                match merged.last_mut() {
+                    Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                    _ => merged.push(range),
+                }
+                merged
+            })
+    }
+
     pub fn range_location(&self) -> u64 {
         self.mappings
             .last()
@@ -218,19 +303,16 @@ impl Almanach {
                 let result = seeds
                     .into_iter()
                     .flat_map(|ps| {
-                        self.seeds
-                            .iter()
-                            .tuples()
-                            .filter_map(move |(&start, &len)| {
-                                let seed = start..start + len;
-                                if seed.contains(&ps.start) {
-                                    Some(ps.start)
-                                } else if ps.contains(&seed.start) {
-                                    Some(seed.start)
-                                } else {
-                                    None
-                                }
-                            })
+                        chunk_pairs(&self.seeds).filter_map(move |(start, len)| {
+                            let seed = start..start + len;
+                            if seed.contains(&ps.start) {
+                                Some(ps.start)
+                            } else if ps.contains(&seed.start) {
+                                Some(seed.start)
+                            } else {
+                                None
+                            }
+                        })
                     })
                     .collect_vec();
                 if result.is_empty() {
@@ -367,4 +449,52 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn map_range() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let almanach: Almanach = input.parse()?;
+
+        assert_eq!(almanach.mappings[0].map_range(&(79..93)), vec![81..95]);
+        assert_eq!(
+            almanach.mappings[0].map_range(&(45..100)),
+            vec![45..50, 50..52, 52..100]
+        );
+        assert_eq!(almanach.mappings[0].map_range(&(10..20)), vec![10..20]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn location_ranges() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let almanach: Almanach = input.parse()?;
+
+        let ranges = almanach.location_ranges();
+        assert_eq!(ranges.first().map(|range| range.start), Some(46));
+        assert_eq!(
+            ranges
+                .iter()
+                .map(|range| range.end - range.start)
+                .sum::<u64>(),
+            27
+        );
+        assert!(ranges
+            .iter()
+            .tuple_windows()
+            .all(|(fst, snd)| fst.end < snd.start));
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlapping() {
+        let input = "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n52 50 49\n";
+        assert!(matches!(
+            input.parse::<Almanach>(),
+            Err(DayError::OverlappingRanges(_, _))
+        ));
+    }
 }
@@ -1,4 +1,4 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use std::{num, str::FromStr};
 
@@ -22,6 +22,11 @@ impl DayTrait for Day {
         let result = table.count_winning();
         Ok(result.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Table = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -40,10 +45,17 @@ struct Race {
     distance: u64,
 }
 
+/// the puzzle's model: holding the button charges the boat by one unit
+/// of speed per millisecond, the remaining time it travels at that speed
+#[inline]
+fn hold_distance(time: u64, hold_time: u64) -> u64 {
+    (time - hold_time) * hold_time
+}
+
 impl Race {
     #[inline]
     pub fn distance(&self, hold_time: u64) -> u64 {
-        (self.time - hold_time) * hold_time
+        hold_distance(self.time, hold_time)
     }
 
     #[allow(dead_code)]
@@ -52,25 +64,63 @@ impl Race {
         (0..=self.time).map(|hold| self.distance(hold))
     }
 
-    fn find_winning_hold(&self, mut min_time: u64, mut max_time: u64, from_shorter: bool) -> u64 {
-        while min_time + 1 < max_time {
+    /// finds the first hold time in min_time..max_time for which
+    /// the predicate no longer holds. The predicate must be true for a
+    /// prefix of that range and false afterwards.
+    fn partition_point<P>(mut min_time: u64, mut max_time: u64, predicate: P) -> u64
+    where
+        P: Fn(u64) -> bool,
+    {
+        while min_time < max_time {
             let middle = (min_time + max_time) / 2;
-            let distance = self.distance(middle);
-            if (distance <= self.distance) == from_shorter {
-                min_time = middle;
+            if predicate(middle) {
+                min_time = middle + 1;
             } else {
                 max_time = middle;
             }
         }
 
-        max_time
+        min_time
+    }
+
+    /// the first and the last winning hold time for any distance model
+    /// that first rises and then falls with the hold time. The model is
+    /// called with the race time and the hold time. If no hold time wins,
+    /// the interval is empty: first is one above last
+    pub fn winning_interval_with<F>(&self, distance: F) -> (u64, u64)
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let distance = |hold_time| distance(self.time, hold_time);
+        let peak = Race::partition_point(0, self.time, |hold| distance(hold) < distance(hold + 1));
+        if distance(peak) <= self.distance {
+            return (peak + 1, peak);
+        }
+
+        let start = Race::partition_point(0, peak, |hold| distance(hold) <= self.distance);
+        let end = Race::partition_point(peak, self.time + 1, |hold| distance(hold) > self.distance);
+        (start, end - 1)
+    }
+
+    /// counts the winning hold times, see winning_interval_with
+    #[cfg(test)]
+    pub fn count_winning_with<F>(&self, distance: F) -> u64
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let (first, last) = self.winning_interval_with(distance);
+        last + 1 - first
+    }
+
+    #[inline]
+    pub fn winning_interval(&self) -> (u64, u64) {
+        self.winning_interval_with(hold_distance)
     }
 
     #[inline]
     pub fn count_winning(&self) -> u64 {
-        let start = self.find_winning_hold(0, self.time / 2, true);
-        let end = self.find_winning_hold(self.time / 2, self.time, false);
-        end - start
+        let (first, last) = self.winning_interval();
+        last + 1 - first
     }
 }
 
@@ -216,6 +266,28 @@ mod test {
         assert_eq!(race.count_winning(), 4);
     }
 
+    #[test]
+    fn winning_interval() {
+        let race = Race {
+            time: 7,
+            distance: 9,
+        };
+        assert_eq!(race.winning_interval(), (2, 5));
+
+        let race = Race {
+            time: 30,
+            distance: 200,
+        };
+        assert_eq!(race.winning_interval(), (11, 19));
+
+        let race = Race {
+            time: 7,
+            distance: 12,
+        };
+        assert_eq!(race.winning_interval(), (4, 3));
+        assert_eq!(race.count_winning(), 0);
+    }
+
     #[test]
     fn all_distances() -> UnitResult {
         let day = Day {};
@@ -237,4 +309,24 @@ mod test {
         assert_eq!(table, expected);
         Ok(())
     }
+
+    #[test]
+    fn other_model() {
+        let race = Race {
+            time: 7,
+            distance: 9,
+        };
+        let accelerate = |time: u64, hold: u64| (time - hold) * hold * hold;
+        let expected = (0..=race.time)
+            .filter(|hold| accelerate(race.time, *hold) > race.distance)
+            .count() as u64;
+        assert_eq!(expected, 5);
+        assert_eq!(race.count_winning_with(accelerate), expected);
+
+        let race = Race {
+            time: 4,
+            distance: 100,
+        };
+        assert_eq!(race.count_winning_with(accelerate), 0);
+    }
 }
@@ -1,8 +1,9 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
-use std::{num, str::FromStr};
+use std::{fmt::Display, num, str::FromStr};
 
 const DAY_NUMBER: DayType = 7;
+const HAND_SIZE: usize = 5;
 
 pub struct Day;
 
@@ -20,6 +21,11 @@ impl DayTrait for Day {
         let game: Game<BetterCard> = input.parse()?;
         Ok(game.winnings().into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Game<RegularCard> = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -43,9 +49,35 @@ enum HandType {
     FiveOfAKind,
 }
 
+impl HandType {
+    fn from_counts(counts: [u8; 13], wildcards: u8) -> HandType {
+        let mut counts = counts.into_iter().sorted_unstable_by(|a, b| b.cmp(a));
+        let first = counts.next().unwrap_or(0) + wildcards;
+        let second = counts.next().unwrap_or(0);
+        match (first, second) {
+            (5.., _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2..) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
 trait Card: Ord + Sized {
     fn from_char(ch: char) -> Result<Self, DayError>;
+    fn to_char(&self) -> char;
+    fn rank(&self) -> usize;
     fn hand_type(hand: &[Self]) -> HandType;
+
+    fn counts(hand: &[Self]) -> [u8; 13] {
+        hand.iter().fold([0; 13], |mut num_cards, card| {
+            num_cards[card.rank()] += 1;
+            num_cards
+        })
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -64,43 +96,32 @@ impl Card for RegularCard {
         }
     }
 
-    fn hand_type(hand: &[Self]) -> HandType {
-        let num_cards = hand.iter().fold(vec![0; 13], |mut num_cards, card| {
-            num_cards[(card.0 as usize) - 2] += 1;
-            num_cards
-        });
-        let max_count = num_cards.iter().max().copied().unwrap();
-        match max_count {
-            1 => HandType::HighCard,
-            2 => {
-                if num_cards.iter().filter(|count| **count == 2).count() == 2 {
-                    HandType::TwoPair
-                } else {
-                    HandType::OnePair
-                }
-            }
-            3 => {
-                if num_cards.contains(&2) {
-                    HandType::FullHouse
-                } else {
-                    HandType::ThreeOfAKind
-                }
-            }
-            4 => HandType::FourOfAKind,
-            5 => HandType::FiveOfAKind,
-            _ => unreachable!(),
+    fn to_char(&self) -> char {
+        match self.0 {
+            10 => 'T',
+            11 => 'J',
+            12 => 'Q',
+            13 => 'K',
+            14 => 'A',
+            digit => char::from_digit(digit, 10).unwrap(),
         }
     }
+
+    #[inline]
+    fn rank(&self) -> usize {
+        self.0 as usize - 2
+    }
+
+    fn hand_type(hand: &[Self]) -> HandType {
+        HandType::from_counts(Self::counts(hand), 0)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct BetterCard(u32);
 
 impl BetterCard {
-    #[inline]
-    fn is_joker(&self) -> bool {
-        matches!(self, BetterCard(1))
-    }
+    const JOKER: BetterCard = BetterCard(1);
 }
 
 impl Card for BetterCard {
@@ -116,80 +137,27 @@ impl Card for BetterCard {
         }
     }
 
-    fn hand_type(hand: &[Self]) -> HandType {
-        let num_cards = hand.iter().fold(vec![0; 13], |mut num_cards, card| {
-            num_cards[(card.0 as usize) - 1] += 1;
-            num_cards
-        });
-        let max_count = num_cards.iter().max().unwrap();
-        let joker_count = hand.iter().filter(|c| c.is_joker()).count();
-        match max_count {
-            1 => {
-                assert!(joker_count < 2);
-                if joker_count == 1 {
-                    HandType::OnePair
-                } else {
-                    HandType::HighCard
-                }
-            }
-            2 => {
-                let pair_count = num_cards.iter().filter(|count| **count == 2).count();
-                match joker_count {
-                    3..=5 => unreachable!(),
-
-                    2 => {
-                        if pair_count == 2 {
-                            HandType::FourOfAKind
-                        } else {
-                            HandType::ThreeOfAKind
-                        }
-                    }
-                    1 => {
-                        if pair_count == 2 {
-                            HandType::FullHouse
-                        } else {
-                            HandType::ThreeOfAKind
-                        }
-                    }
-                    _ => {
-                        if pair_count == 2 {
-                            HandType::TwoPair
-                        } else {
-                            HandType::OnePair
-                        }
-                    }
-                }
-            }
-            3 => match joker_count {
-                3 => {
-                    let pair_count = num_cards.iter().filter(|count| **count == 2).count();
-                    if pair_count == 1 {
-                        HandType::FiveOfAKind
-                    } else {
-                        HandType::FourOfAKind
-                    }
-                }
-                2 => HandType::FiveOfAKind,
-                1 => HandType::FourOfAKind,
-                _ => {
-                    if num_cards.contains(&2) {
-                        HandType::FullHouse
-                    } else {
-                        HandType::ThreeOfAKind
-                    }
-                }
-            },
-            4 => {
-                if joker_count == 1 || joker_count == 4 {
-                    HandType::FiveOfAKind
-                } else {
-                    HandType::FourOfAKind
-                }
-            }
-            5 => HandType::FiveOfAKind,
-            _ => unreachable!(),
+    fn to_char(&self) -> char {
+        match self.0 {
+            1 => 'J',
+            10 => 'T',
+            11 => 'Q',
+            12 => 'K',
+            13 => 'A',
+            digit => char::from_digit(digit, 10).unwrap(),
         }
     }
+
+    #[inline]
+    fn rank(&self) -> usize {
+        self.0 as usize - 1
+    }
+
+    fn hand_type(hand: &[Self]) -> HandType {
+        let mut counts = Self::counts(hand);
+        let jokers = std::mem::take(&mut counts[Self::JOKER.rank()]);
+        HandType::from_counts(counts, jokers)
+    }
 }
 
 #[derive(Debug)]
@@ -213,53 +181,109 @@ impl<C: Card> PartialOrd for Hand<C> {
 
 impl<C: Card> Ord for Hand<C> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self.get_type().cmp(&other.get_type()) {
-            std::cmp::Ordering::Equal => {}
-            ord => return ord,
-        }
-        self.cards.cmp(&other.cards)
+        self.explain_cmp(other).0
     }
 }
 
+/// What decided the order of two hands
+#[derive(Debug, PartialEq, Eq)]
+enum Decision {
+    HandType,
+    Card(usize),
+    Tie,
+}
+
 impl<C: Card> Hand<C> {
     pub fn get_type(&self) -> HandType {
         C::hand_type(&self.cards)
     }
+
+    /// compares like cmp, but also tells if the hand type or the card at
+    /// a specific index made the difference
+    pub fn explain_cmp(&self, other: &Self) -> (std::cmp::Ordering, Decision) {
+        match self.get_type().cmp(&other.get_type()) {
+            std::cmp::Ordering::Equal => {}
+            ord => return (ord, Decision::HandType),
+        }
+        for (idx, (mine, theirs)) in self.cards.iter().zip(other.cards.iter()).enumerate() {
+            match mine.cmp(theirs) {
+                std::cmp::Ordering::Equal => {}
+                ord => return (ord, Decision::Card(idx)),
+            }
+        }
+        match self.cards.len().cmp(&other.cards.len()) {
+            std::cmp::Ordering::Equal => (std::cmp::Ordering::Equal, Decision::Tie),
+            ord => (ord, Decision::Card(self.cards.len().min(other.cards.len()))),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn counts(&self) -> [u8; 13] {
+        C::counts(&self.cards)
+    }
 }
 
 struct Game<C: Card> {
     hands: Vec<Hand<C>>,
 }
 
+impl<C: Card> Display for Hand<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in self.cards.iter() {
+            write!(f, "{}", card.to_char())?;
+        }
+        write!(f, " {}", self.value)
+    }
+}
+
 impl<C: Card> Game<C> {
-    pub fn winnings(&self) -> u64 {
+    /// all hands from the weakest to the strongest, together with their rank
+    fn ranked(&self) -> impl Iterator<Item = (u64, &Hand<C>)> {
         self.hands
             .iter()
             .sorted()
             .enumerate()
-            .map(|(pos, hand)| (pos as u64 + 1) * hand.value)
-            .sum()
+            .map(|(pos, hand)| (pos as u64 + 1, hand))
     }
-}
 
-impl<C: Card> FromStr for Game<C> {
-    type Err = DayError;
+    pub fn winnings(&self) -> u64 {
+        self.ranked().map(|(rank, hand)| rank * hand.value).sum()
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let hands = s.lines().map(|line| line.parse()).try_collect()?;
+    /// every hand with its bid and rank, from the weakest to the strongest
+    #[allow(dead_code)]
+    pub fn leaderboard_strings(&self) -> Vec<String> {
+        self.ranked()
+            .map(|(rank, hand)| format!("{hand} {rank}"))
+            .collect()
+    }
+}
+
+impl<C: Card> Game<C> {
+    pub fn parse_with_hand_size(s: &str, hand_size: usize) -> Result<Self, DayError> {
+        let hands = s
+            .lines()
+            .map(|line| Hand::parse_with_size(line, hand_size))
+            .try_collect()?;
         Ok(Self { hands })
     }
 }
 
-impl<C: Card> FromStr for Hand<C> {
+impl<C: Card> FromStr for Game<C> {
     type Err = DayError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_hand_size(s, HAND_SIZE)
+    }
+}
+
+impl<C: Card> Hand<C> {
+    pub fn parse_with_size(s: &str, size: usize) -> Result<Self, DayError> {
         let Some((hand, value)) = s.split_once(' ') else {
             return Err(DayError::ParseError(s.to_owned()));
         };
         let cards: Vec<_> = hand.chars().map(C::from_char).try_collect()?;
-        if cards.len() != 5 {
+        if cards.len() != size {
             return Err(DayError::ParseError(s.to_owned()));
         }
         Ok(Self {
@@ -269,6 +293,14 @@ impl<C: Card> FromStr for Hand<C> {
     }
 }
 
+impl<C: Card> FromStr for Hand<C> {
+    type Err = DayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_size(s, HAND_SIZE)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -389,4 +421,89 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn counts() -> UnitResult {
+        let input = "33323 1";
+        let hand: Hand<RegularCard> = input.parse()?;
+        let counts = hand.counts();
+        assert_eq!(counts[RegularCard(3).rank()], 4);
+        assert_eq!(counts[RegularCard(2).rank()], 1);
+        assert_eq!(counts.iter().filter(|count| **count != 0).count(), 2);
+
+        let hand: Hand<BetterCard> = input.parse()?;
+        assert_eq!(
+            hand.counts().iter().sorted().rev().take(2).collect_vec(),
+            [&4, &1]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn hand_size() -> UnitResult {
+        let input = "33K 1";
+        assert!(input.parse::<Hand<RegularCard>>().is_err());
+
+        let hand: Hand<RegularCard> = Hand::parse_with_size(input, 3)?;
+        assert_eq!(hand.get_type(), HandType::OnePair);
+
+        let hand: Hand<BetterCard> = Hand::parse_with_size("3JK 1", 3)?;
+        assert_eq!(hand.get_type(), HandType::OnePair);
+
+        let hand: Hand<BetterCard> = Hand::parse_with_size("3J3 1", 3)?;
+        assert_eq!(hand.get_type(), HandType::ThreeOfAKind);
+
+        let game: Game<RegularCard> = Game::parse_with_hand_size("33K 1\n234 2", 3)?;
+        assert_eq!(game.winnings(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tie_break() -> UnitResult {
+        let first: Hand<RegularCard> = "KKT77 1".parse()?;
+        let second: Hand<RegularCard> = "KK677 2".parse()?;
+        assert_eq!(first.get_type(), second.get_type());
+        assert_eq!(
+            first.explain_cmp(&second),
+            (std::cmp::Ordering::Greater, Decision::Card(2))
+        );
+        assert_eq!(
+            second.explain_cmp(&first),
+            (std::cmp::Ordering::Less, Decision::Card(2))
+        );
+        assert!(first > second);
+
+        let third: Hand<RegularCard> = "23456 3".parse()?;
+        assert_eq!(
+            third.explain_cmp(&first),
+            (std::cmp::Ordering::Less, Decision::HandType)
+        );
+        assert_eq!(
+            first.explain_cmp(&first),
+            (std::cmp::Ordering::Equal, Decision::Tie)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn leaderboard() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+
+        let game: Game<RegularCard> = input.parse()?;
+        let board = game.leaderboard_strings();
+        assert_eq!(board.len(), 5);
+        assert_eq!(board.first().map(String::as_str), Some("32T3K 765 1"));
+        assert_eq!(board.last().map(String::as_str), Some("QQQJA 483 5"));
+
+        let game: Game<BetterCard> = input.parse()?;
+        let board = game.leaderboard_strings();
+        assert_eq!(board.first().map(String::as_str), Some("32T3K 765 1"));
+        assert_eq!(board.last().map(String::as_str), Some("KTJJT 220 5"));
+
+        Ok(())
+    }
 }
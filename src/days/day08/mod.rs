@@ -1,8 +1,11 @@
-use std::str::FromStr;
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
-use crate::common::math::lcm;
+use crate::common::math::{chinese_remainder, gcd, lcm};
 
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 
 const DAY_NUMBER: DayType = 8;
@@ -23,6 +26,11 @@ impl DayTrait for Day {
         let network: Network = input.try_into()?;
         Ok(network.count_ghost_steps()?.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Network = input.try_into()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -31,6 +39,8 @@ enum DayError {
     ParseError(String),
     #[error("Node not found: {0}")]
     NodeNotFound(String),
+    #[error("The ghosts never reach their ends at the same time")]
+    NoCommonEnd,
 }
 
 const START: &str = "AAA";
@@ -84,6 +94,42 @@ impl FromStr for Node {
     }
 }
 
+/// the steps at which a ghost reaches an end node. After the
+/// steps in transient the ghost runs in a cycle starting at step start,
+/// where it is at an end whenever steps % length is one of the residues
+struct GhostCycle {
+    transient: Vec<usize>,
+    start: usize,
+    length: usize,
+    residues: Vec<usize>,
+}
+
+impl GhostCycle {
+    fn is_end(&self, steps: usize) -> bool {
+        if steps < self.start {
+            self.transient.contains(&steps)
+        } else {
+            self.residues.contains(&(steps % self.length))
+        }
+    }
+
+    /// the number of steps, if the ghost is at an end exactly every
+    /// that many steps. Then the lcm of all periods is the answer
+    fn period(&self) -> Option<usize> {
+        let period = self
+            .residues
+            .iter()
+            .try_fold(self.length, |period, residue| gcd(period, *residue))?;
+        let simple = self.residues.len() == self.length / period
+            && self
+                .transient
+                .iter()
+                .copied()
+                .eq((period..self.start).step_by(period));
+        simple.then_some(period)
+    }
+}
+
 struct Network<'a> {
     instructions: &'a str,
     nodes: Vec<Node>,
@@ -97,7 +143,7 @@ impl<'a> Network<'a> {
             let middle = (min + max) / 2;
             let node = &self.nodes[middle];
             match &node.name.cmp(name) {
-                std::cmp::Ordering::Less => min = middle,
+                std::cmp::Ordering::Less => min = middle + 1,
                 std::cmp::Ordering::Equal => return Some(node),
                 std::cmp::Ordering::Greater => max = middle,
             }
@@ -106,14 +152,41 @@ impl<'a> Network<'a> {
     }
 
     pub fn count_human_steps(&self) -> Result<usize, DayError> {
-        let Some(mut node) = self.find_node(&String::from(START)) else {
-            return Err(DayError::NodeNotFound(START.to_owned()));
+        self.steps_between(START, |name| name == END)
+    }
+
+    /// the steps needed from the node start to the first node
+    /// accepted by is_end
+    pub fn steps_between<F>(&self, start: &str, is_end: F) -> Result<usize, DayError>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let Some(node) = self.find_node(&start.chars().rev().collect()) else {
+            return Err(DayError::NodeNotFound(start.to_owned()));
         };
+        //names are reversed, is_end gets to see the original ones
+        let ends: HashSet<_> = self
+            .nodes
+            .iter()
+            .filter(|node| is_end(&node.name.chars().rev().collect::<String>()))
+            .map(|node| node.name.as_str())
+            .collect();
+        self.walk_one_path(node, |name| ends.contains(name))
+    }
+
+    /// is_end gets to see the reversed names as they are stored
+    fn walk_one_path<F>(&self, start: &Node, is_end: F) -> Result<usize, DayError>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mut node = start;
         for (steps, turn) in self.instructions.chars().cycle().enumerate() {
             let name = if turn == 'L' { &node.left } else { &node.right };
-            if name == END {
+
+            if is_end(name) {
                 return Ok(steps + 1);
             }
+
             let Some(next_node) = self.find_node(name) else {
                 return Err(DayError::NodeNotFound(name.to_owned()));
             };
@@ -122,30 +195,109 @@ impl<'a> Network<'a> {
         unreachable!()
     }
 
-    fn walk_one_path(&self, start: &Node) -> Result<usize, DayError> {
-        let mut node = start;
-        for (steps, turn) in self.instructions.chars().cycle().enumerate() {
-            let name = if turn == 'L' { &node.left } else { &node.right };
+    /// uses the lcm of the periods if every ghost reaches its ends
+    /// in a clean cycle, and combines all cycles otherwise
+    pub fn count_ghost_steps(&self) -> Result<usize, DayError> {
+        let cycles = self.ghost_cycles()?;
+        if let Some(periods) = cycles
+            .iter()
+            .map(GhostCycle::period)
+            .collect::<Option<Vec<_>>>()
+        {
+            Ok(periods.into_iter().fold(1, lcm))
+        } else {
+            Self::combine_cycles(&cycles)
+        }
+    }
 
-            //names are revers, so string means originally ending with
-            if name.starts_with('Z') {
-                return Ok(steps + 1);
+    fn ghost_cycles(&self) -> Result<Vec<GhostCycle>, DayError> {
+        self.nodes
+            .iter()
+            .filter(|node| node.name.starts_with('A'))
+            .map(|node| self.ghost_cycle(node))
+            .try_collect()
+    }
+
+    fn ghost_cycle(&self, start: &Node) -> Result<GhostCycle, DayError> {
+        let instructions = self.instructions.chars().collect_vec();
+        let mut seen = HashMap::new();
+        let mut ends = vec![];
+        let mut node = start;
+        let mut steps = 0;
+        loop {
+            let idx = steps % instructions.len();
+            if let Some(&first) = seen.get(&(node.name.as_str(), idx)) {
+                let length = steps - first;
+                let (transient, cyclic): (Vec<_>, Vec<_>) =
+                    ends.into_iter().partition(|end| *end < first);
+                let residues = cyclic
+                    .into_iter()
+                    .map(|end| end % length)
+                    .unique()
+                    .collect_vec();
+                return Ok(GhostCycle {
+                    transient,
+                    start: first,
+                    length,
+                    residues,
+                });
             }
+            seen.insert((node.name.as_str(), idx), steps);
 
+            let name = if instructions[idx] == 'L' {
+                &node.left
+            } else {
+                &node.right
+            };
             let Some(next_node) = self.find_node(name) else {
                 return Err(DayError::NodeNotFound(name.to_owned()));
             };
             node = next_node;
+            steps += 1;
+
+            //names are revers, so string means originally ending with
+            if node.name.starts_with('Z') {
+                ends.push(steps);
+            }
         }
-        unreachable!()
     }
 
-    pub fn count_ghost_steps(&self) -> Result<usize, DayError> {
-        self.nodes
+    /// does not assume that every ghost reaches exactly one end node
+    /// in a clean cycle
+    fn combine_cycles(cycles: &[GhostCycle]) -> Result<usize, DayError> {
+        // before all ghosts are in their cycles, just try every step
+        let offset = cycles
             .iter()
-            .filter(|node| node.name.starts_with('A'))
-            .map(|node| self.walk_one_path(node))
-            .fold_ok(1, lcm)
+            .map(|cycle| cycle.start)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        if let Some(steps) = (1..offset).find(|steps| cycles.iter().all(|c| c.is_end(*steps))) {
+            return Ok(steps);
+        }
+
+        let combined = cycles.iter().fold(vec![(0, 1)], |combined, cycle| {
+            combined
+                .into_iter()
+                .cartesian_product(cycle.residues.iter())
+                .filter_map(|(current, residue)| {
+                    chinese_remainder(current, (*residue as i64, cycle.length as i64))
+                })
+                .collect_vec()
+        });
+        let offset = offset as i64;
+        combined
+            .into_iter()
+            .map(|(residue, modulo)| {
+                if residue >= offset {
+                    residue
+                } else {
+                    residue + (offset - residue + modulo - 1) / modulo * modulo
+                }
+            })
+            .min()
+            .map(|steps| steps as usize)
+            .ok_or(DayError::NoCommonEnd)
     }
 }
 
@@ -218,6 +370,23 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn find_node() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+
+        let network: Network = input.as_str().try_into()?;
+        for name in ["AAA", "BBB", "ZZZ"] {
+            let node = network.find_node(&name.to_owned());
+            assert_eq!(node.map(|node| node.name.as_str()), Some(name));
+        }
+        for name in ["", "AA", "AAB", "CCC", "ZZZZ"] {
+            assert!(network.find_node(&name.to_owned()).is_none());
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn walk() -> UnitResult {
         let day = Day {};
@@ -229,6 +398,20 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn between() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+
+        let network: Network = input.as_str().try_into()?;
+        assert_eq!(network.steps_between("BBB", |name| name == "AAA")?, 1);
+        assert_eq!(network.steps_between("BBB", |name| name == "ZZZ")?, 3);
+        assert_eq!(network.steps_between("ZZZ", |name| name.ends_with('Z'))?, 1);
+        assert!(network.steps_between("QQQ", |name| name == "ZZZ").is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn ghost_walk() -> UnitResult {
         let day = Day {};
@@ -239,4 +422,38 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn ghost_walk_general() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example03.txt")?;
+
+        let network: Network = input.as_str().try_into()?;
+        let cycles = network.ghost_cycles()?;
+        let periods = cycles.iter().map(GhostCycle::period).collect_vec();
+        assert_eq!(periods, [Some(2), Some(3)]);
+        assert_eq!(Network::combine_cycles(&cycles)?, 6);
+
+        // the first ghost is at an end every second step from step 2 on,
+        // the second one every third step from step 1 on.
+        let input = "L
+
+AAA = (AAB, XXX)
+AAB = (AAZ, XXX)
+AAZ = (AAC, XXX)
+AAC = (ABZ, XXX)
+ABZ = (AAB, XXX)
+BBA = (BBZ, XXX)
+BBZ = (BBB, XXX)
+BBB = (BBC, XXX)
+BBC = (BBZ, XXX)
+XXX = (XXX, XXX)";
+        let network: Network = input.try_into()?;
+        let cycles = network.ghost_cycles()?;
+        assert_eq!(cycles[0].period(), Some(2));
+        assert_eq!(cycles[1].period(), None);
+        assert_eq!(network.count_ghost_steps()?, 4);
+
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
+use num_traits::{PrimInt, Signed};
 use std::{num, str::FromStr};
 
 const DAY_NUMBER: DayType = 9;
@@ -14,17 +15,24 @@ impl DayTrait for Day {
     fn part1(&self, input: &str) -> RResult {
         let result = input
             .lines()
-            .map(|line| line.parse::<Sequence>())
-            .fold_ok(0, |acc, seq| acc + seq.find_next().1)?;
-        Ok(result.into())
+            .map(|line| line.parse::<Sequence<i128>>())
+            .fold_ok(0, |acc, seq| acc + seq.extrapolate().next)?;
+        Ok(to_integer(result)?.into())
     }
 
     fn part2(&self, input: &str) -> RResult {
         let result = input
             .lines()
-            .map(|line| line.parse::<Sequence>())
-            .fold_ok(0, |acc, seq| acc + seq.find_next().0)?;
-        Ok(result.into())
+            .map(|line| line.parse::<Sequence<i128>>())
+            .fold_ok(0, |acc, seq| acc + seq.extrapolate().previous)?;
+        Ok(to_integer(result)?.into())
+    }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        input
+            .lines()
+            .try_for_each(|line| line.parse::<Sequence<i128>>().map(drop))?;
+        Ok(())
     }
 }
 
@@ -32,19 +40,35 @@ impl DayTrait for Day {
 enum DayError {
     #[error("Not an Int")]
     ParseIntError(#[from] num::ParseIntError),
+    #[error("Result does not fit into an i64: {0}")]
+    ResultOverflow(i128),
+}
+
+/// the sums are calculated with i128, but must fit into an i64 in the end
+fn to_integer(value: i128) -> Result<i64, DayError> {
+    i64::try_from(value).map_err(|_| DayError::ResultOverflow(value))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Extrapolation<T> {
+    previous: T,
+    next: T,
 }
 
-struct Sequence {
-    values: Vec<i64>,
+struct Sequence<T> {
+    values: Vec<T>,
 }
 
-impl Sequence {
-    pub fn find_next(&self) -> (i64, i64) {
+impl<T> Sequence<T>
+where
+    T: PrimInt + Signed,
+{
+    pub fn extrapolate(&self) -> Extrapolation<T> {
         let mut first = vec![];
         let mut last = vec![];
         let mut values = self.values.clone();
 
-        while !values.iter().all(|&v| v == 0) {
+        while !values.iter().all(|v| v.is_zero()) {
             first.push(*values.first().unwrap());
             last.push(*values.last().unwrap());
             values = values
@@ -54,17 +78,26 @@ impl Sequence {
                 .collect_vec();
         }
 
-        (
-            first
+        Extrapolation {
+            previous: first
                 .into_iter()
                 .rev()
-                .fold(0, |new_first, diff_first| diff_first - new_first),
-            last.into_iter().sum::<i64>(),
-        )
+                .fold(T::zero(), |new_first, diff_first| diff_first - new_first),
+            next: last.into_iter().fold(T::zero(), |sum, value| sum + value),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn find_next(&self) -> (T, T) {
+        let Extrapolation { previous, next } = self.extrapolate();
+        (previous, next)
     }
 }
 
-impl FromStr for Sequence {
+impl<T> FromStr for Sequence<T>
+where
+    T: FromStr<Err = num::ParseIntError>,
+{
     type Err = DayError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -106,17 +139,56 @@ mod test {
     #[test]
     fn parse() -> UnitResult {
         let input = "10 13 16 21 30 45";
-        let seq: Sequence = input.parse()?;
+        let seq: Sequence<i64> = input.parse()?;
         assert_eq!(seq.find_next(), (5, 68));
 
         let input = "0 3 6 9 12 15";
-        let seq: Sequence = input.parse()?;
+        let seq: Sequence<i64> = input.parse()?;
         assert_eq!(seq.find_next(), (-3, 18));
 
         let input = "1 3 6 10 15 21";
-        let seq: Sequence = input.parse()?;
+        let seq: Sequence<i64> = input.parse()?;
         assert_eq!(seq.find_next(), (0, 28));
 
         Ok(())
     }
+
+    #[test]
+    fn extrapolate() -> UnitResult {
+        let input = "10 13 16 21 30 45";
+        let seq: Sequence<i64> = input.parse()?;
+        let extrapolation = seq.extrapolate();
+        assert_eq!(extrapolation.previous, 5);
+        assert_eq!(extrapolation.next, 68);
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_values() -> UnitResult {
+        let input = "0 4000000000000000000 8000000000000000000";
+        let seq: Sequence<i128> = input.parse()?;
+        let extrapolation = seq.extrapolate();
+        assert_eq!(extrapolation.previous, -4_000_000_000_000_000_000);
+        assert_eq!(extrapolation.next, 12_000_000_000_000_000_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflowing_sum() -> UnitResult {
+        let day = Day {};
+        // the first line extrapolates to 2^63, which does not fit into an i64
+        let input = "0 4611686018427387904\n0 -2305843009213693952";
+        let result = day.part1(input)?;
+        assert_eq!(result, ResultType::Integer(1 << 62));
+
+        let result = day.part1("0 4611686018427387904");
+        assert!(matches!(
+            result.map_err(|err| err.downcast::<DayError>()),
+            Err(Ok(DayError::ResultOverflow(_)))
+        ));
+
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
 use crate::common::{direction::Direction, pos2::Pos2, turn::Turn};
 
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use std::{num, str::FromStr};
 
@@ -24,6 +24,11 @@ impl DayTrait for Day {
         let enclosed = map.count_enclosed()?;
         Ok(enclosed.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: PipeMap = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -44,6 +49,8 @@ enum DayError {
     IllegalTurns,
     #[error("Can't turn back in loop")]
     CantTurnBack,
+    #[error("Start must connect to exactly two pipes, found {0}")]
+    StartNeedsTwoConnections(usize),
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -77,6 +84,19 @@ impl TryFrom<char> for Pipe {
 }
 
 impl Pipe {
+    pub fn as_char(&self) -> char {
+        match self {
+            Pipe::Start => 'S',
+            Pipe::NorthSouth => '|',
+            Pipe::EastWest => '-',
+            Pipe::NorthEast => 'L',
+            Pipe::NorthWest => 'J',
+            Pipe::SouthWest => '7',
+            Pipe::SouthEast => 'F',
+            Pipe::Ground => '.',
+        }
+    }
+
     pub fn exit(&self, from: Direction) -> Option<Direction> {
         match (self, from) {
             (Pipe::NorthSouth, Direction::North) => Some(Direction::South),
@@ -164,7 +184,22 @@ impl PipeMap {
             Err(_) => return Err(DayError::NeedExactlyOneStart),
         };
 
-        Ok(Self { pipes, start })
+        let map = Self { pipes, start };
+        let connections = map.start_connections().count();
+        if connections != 2 {
+            return Err(DayError::StartNeedsTwoConnections(connections));
+        }
+
+        Ok(map)
+    }
+
+    /// all directions in which the neighbor of the start leads back to it
+    fn start_connections(&self) -> impl Iterator<Item = Direction> + '_ {
+        Direction::iter().filter(|dir| {
+            self.start
+                .safe_matrix_add_and_get(&self.pipes, *dir)
+                .is_some_and(|(_, pipe)| pipe.exit(dir.turn_back()).is_some())
+        })
     }
 
     pub fn analyze_loop(&self) -> Result<LoopAnalysis, DayError> {
@@ -211,7 +246,56 @@ impl PipeMap {
         }
     }
 
+    /// all positions of the loop in walking order, beginning at the start
+    #[allow(dead_code)]
+    pub fn loop_path(&self) -> Result<Vec<Pos2<usize>>, DayError> {
+        let mut exit = self.analyze_loop()?.exit;
+        let mut pos = self.start;
+        let mut path = vec![pos];
+        while let Some((current, pipe)) = pos.safe_matrix_add_and_get(&self.pipes, exit) {
+            if matches!(pipe, Pipe::Start) {
+                return Ok(path);
+            }
+            let Some(next_exit) = pipe.exit(exit.turn_back()) else {
+                // This can actually never happen, we were here before!
+                break;
+            };
+            path.push(current);
+            exit = next_exit;
+            pos = current;
+        }
+        Err(DayError::NoLoopFound)
+    }
+
     pub fn count_enclosed(&self) -> Result<usize, DayError> {
+        Ok(self
+            .enclosed_markings()?
+            .into_iter()
+            .flat_map(|row| row.into_iter().filter(|mark| matches!(mark, Mark::Inside)))
+            .count())
+    }
+
+    #[allow(dead_code)]
+    pub fn render(&self) -> Result<String, DayError> {
+        Ok(self
+            .enclosed_markings()?
+            .iter()
+            .zip(self.pipes.iter())
+            .map(|(marks, pipes)| {
+                marks
+                    .iter()
+                    .zip(pipes.iter())
+                    .map(|(mark, pipe)| match mark {
+                        Mark::Loop => pipe.as_char(),
+                        Mark::Inside => 'I',
+                        Mark::Unknown => 'O',
+                    })
+                    .collect::<String>()
+            })
+            .join("\n"))
+    }
+
+    fn enclosed_markings(&self) -> Result<Vec<Vec<Mark>>, DayError> {
         let LoopAnalysis {
             steps: _,
             mut exit,
@@ -220,16 +304,10 @@ impl PipeMap {
         } = self.analyze_loop()?;
         let mut pos = self.start;
 
-        loop {
-            let Some((current, pipe)) = pos.safe_matrix_add_and_get(&self.pipes, exit) else {
-                // This can actually never happen, we were here before!
-                break;
-            };
+        // Running off the map can actually never happen, we were here before!
+        while let Some((current, pipe)) = pos.safe_matrix_add_and_get(&self.pipes, exit) {
             if matches!(pipe, Pipe::Start) {
-                return Ok(markings
-                    .into_iter()
-                    .flat_map(|row| row.into_iter().filter(|mark| matches!(mark, Mark::Inside)))
-                    .count());
+                return Ok(markings);
             }
             let Some(next_exit) = pipe.exit(exit.turn_back()) else {
                 // This can actually never happen, we were here before!
@@ -370,6 +448,21 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn render() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example03.txt")?;
+        let map: PipeMap = input.parse()?;
+
+        let rendered = map.render()?;
+        assert_eq!(rendered.chars().filter(|c| *c == 'I').count(), 8);
+        assert_eq!(rendered.lines().count(), 10);
+        assert!(rendered.lines().all(|line| line.len() == 20));
+        assert_eq!(rendered.lines().next(), Some("OF----7F7F7F7F-7OOOO"));
+
+        Ok(())
+    }
+
     #[test]
     fn example4() -> UnitResult {
         let day = Day {};
@@ -380,4 +473,34 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn loop_path() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let map: PipeMap = input.parse()?;
+
+        let path = map.loop_path()?;
+        assert_eq!(path.len(), map.analyze_loop()?.steps);
+        assert_eq!(path.first(), Some(&map.start));
+        assert!(path
+            .iter()
+            .circular_tuple_windows()
+            .all(|(fst, snd)| fst.x().abs_diff(snd.x()) + fst.y().abs_diff(snd.y()) == 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ambiguous_start() {
+        let input = ".....
+.F7..
+.|S-7
+.L|.|
+..L-J";
+        assert!(matches!(
+            input.parse::<PipeMap>(),
+            Err(DayError::StartNeedsTwoConnections(3))
+        ));
+    }
 }
@@ -1,6 +1,6 @@
 use crate::common::pos2::Pos2;
 
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use std::{num, str::FromStr};
 
@@ -22,6 +22,11 @@ impl DayTrait for Day {
         let map: GalaxyMap = input.parse()?;
         Ok(map.sum_old(1_000_000).into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: GalaxyMap = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -101,19 +106,32 @@ impl GalaxyMap {
         (mn..mx).filter(|num| lst.contains(num)).count()
     }
 
-    pub fn distances(&self, expansion: usize) -> Vec<usize> {
+    fn expanded_distance(&self, fst: Pos2<usize>, snd: Pos2<usize>, expansion: usize) -> usize {
         let factor = expansion - 1;
+        let dist = fst.taxicab_between(snd);
+        let add_row = Self::count_free_space(fst.y(), snd.y(), &self.empty_rows) * factor;
+        let add_col = Self::count_free_space(fst.x(), snd.x(), &self.empty_cols) * factor;
+        dist + add_row + add_col
+    }
+
+    pub fn distances(&self, expansion: usize) -> Vec<usize> {
         self.galaxies
             .iter()
             .tuple_combinations()
-            .map(|(fst, snd)| {
-                let dist = fst.taxicab_between(*snd);
-                let add_row = Self::count_free_space(fst.y(), snd.y(), &self.empty_rows) * factor;
-                let add_col = Self::count_free_space(fst.x(), snd.x(), &self.empty_cols) * factor;
-                dist + add_row + add_col
-            })
+            .map(|(fst, snd)| self.expanded_distance(*fst, *snd, expansion))
             .collect_vec()
     }
+
+    /// the distance between the galaxies with the given indices. Returns an
+    /// Option instead of a plain usize, so callers can query any index
+    /// without a panic: None if there are not that many galaxies or the
+    /// expansion is 0
+    #[allow(dead_code)]
+    pub fn distance_between(&self, a: usize, b: usize, expansion: usize) -> Option<usize> {
+        let fst = *self.galaxies.get(a)?;
+        let snd = *self.galaxies.get(b)?;
+        (expansion > 0).then(|| self.expanded_distance(fst, snd, expansion))
+    }
 }
 
 #[cfg(test)]
@@ -157,4 +175,20 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn single_distance() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+
+        let map: GalaxyMap = input.parse()?;
+        assert_eq!(map.distance_between(4, 8, 2), Some(9));
+        assert_eq!(map.distance_between(4, 8, 10), Some(25));
+        assert_eq!(map.distance_between(8, 4, 10), Some(25));
+        assert_eq!(map.distance_between(2, 2, 10), Some(0));
+        assert_eq!(map.distance_between(4, 9, 10), None);
+        assert_eq!(map.distance_between(4, 8, 0), None);
+
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
-use std::{collections::HashMap, num, ops::Add, str::FromStr};
+use std::{collections::HashMap, num, str::FromStr};
 
 const DAY_NUMBER: DayType = 12;
 
@@ -12,21 +12,21 @@ impl DayTrait for Day {
     }
 
     fn part1(&self, input: &str) -> RResult {
-        let result = input
-            .lines()
-            .map(|line| line.parse::<SpringList>())
-            .map_ok(|sl| sl.get_arrangements())
-            .fold_ok(0, Add::add)?;
-        Ok(result.into())
+        let lists: Vec<SpringList> = input.lines().map(str::parse).try_collect()?;
+        Ok(SpringList::sum_arrangements(&lists).into())
     }
 
     fn part2(&self, input: &str) -> RResult {
-        let result = input
+        let lists = input
             .lines()
-            .map(|line| line.parse::<SpringList>())
-            .map_ok(|sl| sl.get_long_arrangements())
-            .fold_ok(0, Add::add)?;
-        Ok(result.into())
+            .map(|line| line.parse::<SpringList>().map(|sl| sl.unfold()))
+            .try_collect::<_, Vec<_>, _>()?;
+        Ok(SpringList::sum_arrangements(&lists).into())
+    }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Vec<SpringList> = input.lines().map(str::parse).try_collect()?;
+        Ok(())
     }
 }
 
@@ -71,17 +71,43 @@ impl TryFrom<char> for Icon {
     }
 }
 
+/// remembers the arrangements of whole lines, so lines with the same
+/// pattern are only counted once. Borrows the lines, so they have to outlive
+/// the memo
+type SharedMemo<'a> = HashMap<(&'a [Icon], &'a [u64]), u64>;
+
 struct SpringList {
     as_icon: Vec<Icon>,
     as_list: Vec<u64>,
 }
 
 impl SpringList {
+    /// arrangements of this line alone, without a memo shared with other lines
+    #[allow(dead_code)]
     pub fn get_arrangements(&self) -> u64 {
         RefSpringList::new(&self.as_icon, &self.as_list).start_sub()
     }
 
+    #[allow(dead_code)]
     pub fn get_long_arrangements(&self) -> u64 {
+        self.unfold().get_arrangements()
+    }
+
+    /// sums up the arrangements of all lists, reusing the results of
+    /// earlier lines
+    pub fn sum_arrangements(lists: &[SpringList]) -> u64 {
+        let mut memo = SharedMemo::new();
+        lists
+            .iter()
+            .map(|sl| {
+                *memo
+                    .entry((&sl.as_icon, &sl.as_list))
+                    .or_insert_with(|| RefSpringList::new(&sl.as_icon, &sl.as_list).start_sub())
+            })
+            .sum()
+    }
+
+    fn unfold(&self) -> SpringList {
         let as_icon = self
             .as_icon
             .iter()
@@ -97,7 +123,45 @@ impl SpringList {
             .cycle()
             .take(self.as_list.len() * 5)
             .collect_vec();
-        RefSpringList::new(&as_icon, &as_list).start_sub()
+        SpringList { as_icon, as_list }
+    }
+
+    /// tries every possible replacement of the unknown icons. Only meant to
+    /// check get_arrangements, so None if there are too many unknowns
+    #[cfg(test)]
+    pub fn brute_force_arrangements(&self) -> Option<u64> {
+        const MAX_UNKNOWNS: usize = 20;
+        let unknowns = self
+            .as_icon
+            .iter()
+            .positions(|icon| matches!(icon, Icon::Unknown))
+            .collect_vec();
+        if unknowns.len() > MAX_UNKNOWNS {
+            return None;
+        }
+
+        let count = (0..1u64 << unknowns.len())
+            .filter(|damaged| {
+                let groups = self
+                    .as_icon
+                    .iter()
+                    .enumerate()
+                    .map(|(pos, icon)| match icon {
+                        Icon::Unknown => {
+                            let bit = unknowns.iter().position(|u| *u == pos).unwrap();
+                            damaged & (1 << bit) != 0
+                        }
+                        Icon::Damaged => true,
+                        Icon::Operational => false,
+                    })
+                    .dedup_with_count()
+                    .filter(|(_, damaged)| *damaged)
+                    .map(|(count, _)| count as u64)
+                    .collect_vec();
+                groups == self.as_list
+            })
+            .count();
+        Some(count as u64)
     }
 }
 
@@ -314,4 +378,48 @@ mod test {
         assert_eq!(list.get_long_arrangements(), 2500);
         Ok(())
     }
+
+    #[test]
+    fn brute_force() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        for line in input.lines() {
+            let list: SpringList = line.parse()?;
+            assert_eq!(
+                list.brute_force_arrangements(),
+                Some(list.get_arrangements())
+            );
+        }
+
+        let list: SpringList = "????????????????????? 1".parse()?;
+        assert_eq!(list.brute_force_arrangements(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_memo() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let lists: Vec<SpringList> = input.lines().map(str::parse).try_collect()?;
+        let long_lists = lists.iter().map(SpringList::unfold).collect_vec();
+
+        assert_eq!(
+            SpringList::sum_arrangements(&lists),
+            lists.iter().map(SpringList::get_arrangements).sum()
+        );
+        assert_eq!(
+            SpringList::sum_arrangements(&long_lists),
+            lists.iter().map(SpringList::get_long_arrangements).sum()
+        );
+
+        let twice = input.lines().chain(input.lines()).map(str::parse);
+        let twice: Vec<SpringList> = twice.try_collect()?;
+        assert_eq!(
+            SpringList::sum_arrangements(&twice),
+            2 * SpringList::sum_arrangements(&lists)
+        );
+
+        Ok(())
+    }
 }
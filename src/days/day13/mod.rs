@@ -1,4 +1,6 @@
-use super::{DayTrait, DayType, RResult};
+use crate::common::helper::find_reflection;
+
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use std::{num, str::FromStr};
 
@@ -20,6 +22,11 @@ impl DayTrait for Day {
         let pl: PatternList = input.parse()?;
         Ok(pl.get_evaluation(1).into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: PatternList = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -37,36 +44,8 @@ struct Pattern {
 }
 
 impl Pattern {
-    fn count_smudges(fst: &[bool], snd: &[bool]) -> usize {
-        fst.iter()
-            .zip(snd.iter())
-            .filter(|(fst, snd)| fst != snd)
-            .count()
-    }
-
     fn check_horizontal(&self, expected_smudges: usize) -> Option<usize> {
-        self.dots
-            .iter()
-            .enumerate()
-            .tuple_windows()
-            .filter_map(|((pos, fst), (_, snd))| {
-                if Self::count_smudges(fst, snd) <= expected_smudges {
-                    Some(pos)
-                } else {
-                    None
-                }
-            })
-            .find_map(|row| {
-                let end = row.min(self.dots.len() - (row + 2));
-                let smudges: usize = (0..=end)
-                    .map(|r| Self::count_smudges(&self.dots[row - r], &self.dots[row + r + 1]))
-                    .sum();
-                if smudges == expected_smudges {
-                    Some(row + 1)
-                } else {
-                    None
-                }
-            })
+        find_reflection(&self.dots, expected_smudges)
     }
 
     fn check_vertical(&self, expected_smudges: usize) -> Option<usize> {
@@ -191,4 +170,16 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn smudge_differs_from_clean() -> UnitResult {
+        let input = "#..\n#..\n.#.\n.#.\n#..\n##.\n";
+
+        let pl: PatternList = input.parse()?;
+        assert_eq!(pl.list[0].check_horizontal(0), Some(1));
+        assert_eq!(pl.list[0].check_horizontal(1), Some(3));
+        assert_eq!(pl.get_evaluation(1), 300);
+
+        Ok(())
+    }
 }
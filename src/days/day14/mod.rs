@@ -1,6 +1,6 @@
 use crate::common::{direction::Direction, pos2::Pos2};
 
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use std::{collections::HashMap, str::FromStr};
 
@@ -25,6 +25,11 @@ impl DayTrait for Day {
 
         Ok(field.northern_load_after(1_000_000_000).into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Platform = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -35,6 +40,8 @@ enum DayError {
     NeedRectanglePlatform,
     #[error("Platform must not be empty")]
     EmptyPlatform,
+    #[error("Directions must be perpendicular: {0} and {1}")]
+    NotPerpendicular(Direction, Direction),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -87,7 +94,17 @@ impl Platform {
         self.roll_to(Direction::East);
     }
 
+    fn has_round_rocks(&self) -> bool {
+        self.rocks
+            .iter()
+            .any(|row| row.iter().any(|rock| matches!(rock, Rock::Round)))
+    }
+
     fn northern_load_after(&mut self, cycles: usize) -> usize {
+        if !self.has_round_rocks() {
+            // nothing will ever move or carry load
+            return 0;
+        }
         let mut seen: HashMap<Self, usize> = HashMap::new();
         let mut round = 0;
         while round < cycles {
@@ -145,6 +162,17 @@ impl Platform {
         }
     }
 
+    /// rolls first toward primary, then toward secondary
+    #[allow(dead_code)]
+    fn roll_diagonal(&mut self, primary: Direction, secondary: Direction) -> Result<(), DayError> {
+        if !primary.is_perpendicular(&secondary) {
+            return Err(DayError::NotPerpendicular(primary, secondary));
+        }
+        self.roll_to(primary);
+        self.roll_to(secondary);
+        Ok(())
+    }
+
     fn calc_load(&self) -> usize {
         let len = self.rocks[0].len();
         self.rocks
@@ -202,4 +230,35 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn no_round_rocks() -> UnitResult {
+        let mut field: Platform = "#..\n.#.\n...".parse()?;
+        assert!(!field.has_round_rocks());
+        assert_eq!(field.northern_load_after(1_000_000_000), 0);
+        assert_eq!(field, "#..\n.#.\n...".parse()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn diagonal() -> UnitResult {
+        let mut field: Platform = "...\nO#.\n..O".parse()?;
+        field.roll_diagonal(Direction::North, Direction::East)?;
+        assert_eq!(field, ".OO\n.#.\n...".parse()?);
+
+        let mut field: Platform = "...\nO#.\n..O".parse()?;
+        field.roll_diagonal(Direction::East, Direction::North)?;
+        assert_eq!(field, "O.O\n.#.\n...".parse()?);
+
+        assert!(matches!(
+            field.roll_diagonal(Direction::North, Direction::South),
+            Err(DayError::NotPerpendicular(
+                Direction::North,
+                Direction::South
+            ))
+        ));
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use std::num;
 
@@ -20,6 +20,13 @@ impl DayTrait for Day {
         let seq: Sequence = input.into();
         Ok(seq.focus_power()?.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let seq: Sequence = input.into();
+        seq.instructions()
+            .try_for_each(|instruction| instruction.map(drop))?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -35,6 +42,12 @@ enum Instruction<'a> {
     Remove(&'a str),
 }
 
+/// the HASH algorithm of the puzzle, usable on any data
+pub fn hash_bytes(data: &[u8]) -> usize {
+    data.iter()
+        .fold(0, |hash, byte| ((hash + (*byte as usize)) * 17) % 256)
+}
+
 struct Step<'a> {
     instruction: &'a str,
 }
@@ -49,11 +62,10 @@ impl<'a> Step<'a> {
     }
 
     pub fn hash_me(data: &str) -> usize {
-        data.chars()
-            .fold(0, |hash, c| ((hash + (c as usize)) * 17) % 256)
+        hash_bytes(data.as_bytes())
     }
 
-    pub fn as_instruction(&self) -> Result<Instruction, DayError> {
+    pub fn as_instruction(&self) -> Result<Instruction<'_>, DayError> {
         if let Some((lens, focal)) = self.instruction.split_once('=') {
             Ok(Instruction::Add(lens, focal.parse()?))
         } else if let Some(lens) = self.instruction.strip_suffix('-') {
@@ -73,10 +85,15 @@ impl Sequence<'_> {
         self.steps.iter().map(|s| s.full_hash()).sum()
     }
 
-    fn as_boxes(&self) -> Result<Vec<Box>, DayError> {
+    pub fn instructions(&self) -> impl Iterator<Item = Result<Instruction<'_>, DayError>> {
+        self.steps.iter().map(Step::as_instruction)
+    }
+
+    /// all 256 boxes with their lenses after running the sequence
+    pub fn final_boxes(&self) -> Result<Vec<Box<'_>>, DayError> {
         let mut boxes = vec![Box::new(); 256];
-        for step in self.steps.iter() {
-            match step.as_instruction()? {
+        for instruction in self.instructions() {
+            match instruction? {
                 Instruction::Add(lens, focal) => boxes[Step::hash_me(lens)].add_lens(lens, focal),
                 Instruction::Remove(lens) => boxes[Step::hash_me(lens)].remove_lens(lens),
             }
@@ -86,7 +103,7 @@ impl Sequence<'_> {
 
     pub fn focus_power(&self) -> Result<u32, DayError> {
         Ok(self
-            .as_boxes()?
+            .final_boxes()?
             .into_iter()
             .enumerate()
             .map(|(pos, boxed)| ((pos + 1) as u32) * boxed.focus_power())
@@ -132,6 +149,11 @@ impl Box<'_> {
 }
 
 impl<'a> Box<'a> {
+    #[allow(dead_code)]
+    pub fn lenses(&self) -> &[(&'a str, u32)] {
+        &self.lenses
+    }
+
     pub fn add_lens(&mut self, lens: &'a str, focal: u32) {
         for (old_lens, old_focal) in self.lenses.iter_mut() {
             if old_lens == &lens {
@@ -170,6 +192,16 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn parse_only() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        day.parse_only(&input)?;
+        assert!(day.parse_only("rn=1,cm+2").is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn hash() {
         let input = "HASH";
@@ -177,17 +209,39 @@ mod test {
         assert_eq!(step.full_hash(), 52);
     }
 
+    #[test]
+    fn hash_of_bytes() {
+        assert_eq!(hash_bytes(b"HASH"), 52);
+        assert_eq!(hash_bytes(b""), 0);
+        assert_eq!(hash_bytes(&[0xff]), 239);
+    }
+
     #[test]
     fn boxes() -> UnitResult {
         let day = Day {};
         let input = read_string(day.get_day_number(), "example01.txt")?;
         let seq: Sequence = input.as_str().into();
 
-        let boxes = seq.as_boxes()?;
+        let boxes = seq.final_boxes()?;
         assert_eq!(boxes[0].lenses, [("rn", 1), ("cm", 2)]);
 
         assert_eq!(seq.focus_power()?, 145);
 
         Ok(())
     }
+
+    #[test]
+    fn final_boxes() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let seq: Sequence = input.as_str().into();
+
+        let boxes = seq.final_boxes()?;
+        assert_eq!(boxes.len(), 256);
+        assert_eq!(boxes[0].lenses(), [("rn", 1), ("cm", 2)]);
+        assert!(boxes[1].lenses().is_empty());
+        assert_eq!(boxes[3].lenses(), [("ot", 7), ("ab", 5), ("pc", 6)]);
+
+        Ok(())
+    }
 }
@@ -1,5 +1,8 @@
-use super::{DayTrait, DayType, RResult};
-use crate::common::{direction::Direction, pos2::Pos2};
+use super::{DayTrait, DayType, RResult, UnitResult};
+use crate::common::{
+    direction::{DirMap, Direction},
+    pos2::Pos2,
+};
 use itertools::Itertools;
 use std::{
     collections::{HashMap, HashSet},
@@ -29,6 +32,11 @@ impl DayTrait for Day {
         let best_all = contraption.best_all();
         Ok(best_all.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Contraption = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -66,9 +74,24 @@ impl TryFrom<char> for Mirror {
     }
 }
 
+/// where a beam stops when following it from one splitter to the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BeamEnd {
+    Splitter(Pos2<usize>),
+    Exited,
+    Looped,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct BeamStats {
+    energized: usize,
+    looped: bool,
+}
+
 struct MirrorPath {
     end_points: Vec<Pos2<usize>>,
     energized: HashSet<Pos2<usize>>,
+    looped: bool,
 }
 
 struct Contraption {
@@ -100,45 +123,119 @@ impl Contraption {
     }
 
     fn single_beam(&self, start: Pos2<usize>, direction: Direction) -> usize {
-        let (pos, mut energized) = self.follow_beam(start, direction);
-        let Some(pos) = pos else {
-            return energized.len();
+        self.beam_stats(start, direction).energized
+    }
+
+    /// like single_beam, but also tells if any part of the beam
+    /// runs in a loop instead of leaving the contraption
+    fn beam_stats(&self, start: Pos2<usize>, direction: Direction) -> BeamStats {
+        let (end, mut energized) = self.follow_beam(start, direction);
+        let pos = match end {
+            BeamEnd::Splitter(pos) => pos,
+            BeamEnd::Exited | BeamEnd::Looped => {
+                return BeamStats {
+                    energized: energized.len(),
+                    looped: end == BeamEnd::Looped,
+                }
+            }
         };
-        let mut seen = vec![];
+        let mut looped = false;
+        let mut seen = HashSet::new();
         let mut queue = vec![pos];
         while let Some(pos) = queue.pop() {
-            if seen.contains(&pos) {
+            if !seen.insert(pos) {
                 continue;
             }
-            seen.push(pos);
             let info = self.mirror_path.get(&pos).unwrap();
             energized.extend(&info.energized);
             queue.extend(&info.end_points);
+            looped |= info.looped;
+        }
+        BeamStats {
+            energized: energized.len(),
+            looped: looped || self.has_cycle(&seen),
         }
-        energized.len()
+    }
+
+    /// Beams reaching the same splitter twice may just merge. They only
+    /// loop if the splitters lead back to themselves, so we check for
+    /// a cycle by removing splitters nobody leads to as long as possible
+    fn has_cycle(&self, splitters: &HashSet<Pos2<usize>>) -> bool {
+        let mut incoming: HashMap<_, usize> = splitters.iter().map(|pos| (*pos, 0)).collect();
+        for pos in splitters {
+            for end_point in &self.mirror_path[pos].end_points {
+                *incoming.get_mut(end_point).unwrap() += 1;
+            }
+        }
+        let mut queue = incoming
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(pos, _)| *pos)
+            .collect_vec();
+        let mut removed = 0;
+        while let Some(pos) = queue.pop() {
+            removed += 1;
+            for end_point in &self.mirror_path[&pos].end_points {
+                let count = incoming.get_mut(end_point).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    queue.push(*end_point);
+                }
+            }
+        }
+        removed < splitters.len()
     }
 
     fn best_all(&self) -> usize {
         let height = self.mirrors.len();
         let width = self.mirrors[0].len();
-        let north = (0..width)
-            .map(|x| self.single_beam(Pos2::new(x, 0), Direction::South))
-            .max()
-            .unwrap();
-        let south = (0..width)
-            .map(|x| self.single_beam(Pos2::new(x, height - 1), Direction::North))
-            .max()
-            .unwrap();
-        let west = (0..height)
-            .map(|y| self.single_beam(Pos2::new(0, y), Direction::East))
-            .max()
-            .unwrap();
-        let east = (0..height)
-            .map(|y| self.single_beam(Pos2::new(width - 1, y), Direction::West))
-            .max()
-            .unwrap();
-
-        vec![north, south, west, east].into_iter().max().unwrap()
+        // the best result for beams entering at each edge
+        let maxima = DirMap::from_fn(|edge| {
+            let starts = match edge {
+                Direction::North => (0..width).map(|x| Pos2::new(x, 0)).collect_vec(),
+                Direction::South => (0..width).map(|x| Pos2::new(x, height - 1)).collect_vec(),
+                Direction::West => (0..height).map(|y| Pos2::new(0, y)).collect_vec(),
+                Direction::East => (0..height).map(|y| Pos2::new(width - 1, y)).collect_vec(),
+            };
+            starts
+                .into_iter()
+                .map(|start| self.single_beam(start, edge.turn_back()))
+                .max()
+                .unwrap()
+        });
+
+        maxima.iter().map(|(_, max)| *max).max().unwrap()
+    }
+
+    #[cfg(test)]
+    fn energize_bruteforce(&self, start: Pos2<usize>, direction: Direction) -> usize {
+        let mut seen = HashSet::new();
+        let mut queue = vec![(start, direction)];
+        while let Some((pos, direction)) = queue.pop() {
+            if !seen.insert((pos, direction)) {
+                continue;
+            }
+            let next_directions = match pos.matrix_get(&self.mirrors) {
+                Mirror::None => vec![direction],
+                Mirror::Horizontal if direction.is_vertical() => {
+                    vec![Direction::East, Direction::West]
+                }
+                Mirror::Vertical if direction.is_horizontal() => {
+                    vec![Direction::North, Direction::South]
+                }
+                Mirror::Horizontal | Mirror::Vertical => vec![direction],
+                Mirror::UpRight if direction.is_horizontal() => vec![direction.turn_left()],
+                Mirror::UpRight => vec![direction.turn_right()],
+                Mirror::UpLeft if direction.is_vertical() => vec![direction.turn_left()],
+                Mirror::UpLeft => vec![direction.turn_right()],
+            };
+            for next_direction in next_directions {
+                if let Some(next_pos) = pos.safe_matrix_add(&self.mirrors, next_direction) {
+                    queue.push((next_pos, next_direction));
+                }
+            }
+        }
+        first_item(seen).len()
     }
 
     fn follow_mirrors(&mut self) {
@@ -151,18 +248,20 @@ impl Contraption {
                         let (out_east, mut energized) = self.follow_beam(pos, Direction::East);
                         let (out_west, energized_west) = self.follow_beam(pos, Direction::West);
                         let mut end_points = vec![];
-                        if let Some(east) = out_east {
+                        if let BeamEnd::Splitter(east) = out_east {
                             end_points.push(east);
                         }
-                        if let Some(west) = out_west {
+                        if let BeamEnd::Splitter(west) = out_west {
                             end_points.push(west);
                         }
+                        let looped = out_east == BeamEnd::Looped || out_west == BeamEnd::Looped;
                         energized.extend(energized_west);
                         self.mirror_path.insert(
                             pos,
                             MirrorPath {
                                 end_points,
                                 energized,
+                                looped,
                             },
                         );
                     }
@@ -171,18 +270,20 @@ impl Contraption {
                         let (out_north, mut energized) = self.follow_beam(pos, Direction::North);
                         let (out_south, energized_south) = self.follow_beam(pos, Direction::South);
                         let mut end_points = vec![];
-                        if let Some(north) = out_north {
+                        if let BeamEnd::Splitter(north) = out_north {
                             end_points.push(north);
                         }
-                        if let Some(south) = out_south {
+                        if let BeamEnd::Splitter(south) = out_south {
                             end_points.push(south);
                         }
+                        let looped = out_north == BeamEnd::Looped || out_south == BeamEnd::Looped;
                         energized.extend(energized_south);
                         self.mirror_path.insert(
                             pos,
                             MirrorPath {
                                 end_points,
                                 energized,
+                                looped,
                             },
                         );
                     }
@@ -195,20 +296,23 @@ impl Contraption {
         &self,
         mut pos: Pos2<usize>,
         mut direction: Direction,
-    ) -> (Option<Pos2<usize>>, HashSet<Pos2<usize>>) {
+    ) -> (BeamEnd, HashSet<Pos2<usize>>) {
         let mut touched = vec![];
         let mut mirror = pos.safe_matrix_get(&self.mirrors).unwrap();
         loop {
+            // remember the direction we entered with, a mirror might be
+            // entered again from another side without being in a loop
+            touched.push((pos, direction));
             match mirror {
                 Mirror::None => {}
                 Mirror::Horizontal => {
                     if direction.is_vertical() {
-                        return (Some(pos), first_item(touched));
+                        return (BeamEnd::Splitter(pos), first_item(touched));
                     }
                 }
                 Mirror::Vertical => {
                     if direction.is_horizontal() {
-                        return (Some(pos), first_item(touched));
+                        return (BeamEnd::Splitter(pos), first_item(touched));
                     }
                 }
                 Mirror::UpRight => {
@@ -227,17 +331,16 @@ impl Contraption {
                 }
             }
 
-            touched.push((pos, direction));
             if let Some((next_pos, next_mirror)) =
                 pos.safe_matrix_add_and_get(&self.mirrors, direction)
             {
                 if touched.contains(&(next_pos, direction)) {
-                    return (None, first_item(touched));
+                    return (BeamEnd::Looped, first_item(touched));
                 }
                 pos = next_pos;
                 mirror = next_mirror;
             } else {
-                return (None, first_item(touched));
+                return (BeamEnd::Exited, first_item(touched));
             }
         }
     }
@@ -282,4 +385,116 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn bruteforce() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let contraption: Contraption = input.parse()?;
+
+        let starts = [
+            (Pos2::new(0, 0), Direction::East),
+            (Pos2::new(3, 0), Direction::South),
+            (Pos2::new(9, 5), Direction::West),
+            (Pos2::new(1, 9), Direction::North),
+            (Pos2::new(4, 4), Direction::East),
+        ];
+        for (start, direction) in starts {
+            assert_eq!(
+                contraption.single_beam(start, direction),
+                contraption.energize_bruteforce(start, direction)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn all_edges_unchanged() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let contraption: Contraption = input.parse()?;
+
+        for x in 0..10 {
+            for (start, direction) in [
+                (Pos2::new(x, 0), Direction::South),
+                (Pos2::new(x, 9), Direction::North),
+                (Pos2::new(0, x), Direction::East),
+                (Pos2::new(9, x), Direction::West),
+            ] {
+                assert_eq!(
+                    contraption.single_beam(start, direction),
+                    contraption.energize_bruteforce(start, direction)
+                );
+            }
+        }
+        assert_eq!(contraption.best_all(), 51);
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_grid() -> UnitResult {
+        let tiles = ['.', '.', '.', '.', '/', '\\', '|', '-'];
+        let mut state: u32 = 16;
+        let input = (0..80)
+            .map(|_| {
+                (0..80)
+                    .map(|_| {
+                        state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                        tiles[(state >> 16) as usize % tiles.len()]
+                    })
+                    .collect::<String>()
+            })
+            .join("\n");
+        let contraption: Contraption = input.parse()?;
+
+        for (start, direction) in [
+            (Pos2::new(0, 0), Direction::East),
+            (Pos2::new(40, 79), Direction::North),
+            (Pos2::new(79, 13), Direction::West),
+        ] {
+            assert_eq!(
+                contraption.single_beam(start, direction),
+                contraption.energize_bruteforce(start, direction)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn loop_stats() -> UnitResult {
+        let contraption: Contraption = "/.\\\n...\n\\./".parse()?;
+        let expected = BeamStats {
+            energized: 8,
+            looped: true,
+        };
+        assert_eq!(
+            contraption.beam_stats(Pos2::new(1, 0), Direction::East),
+            expected
+        );
+
+        let expected = BeamStats {
+            energized: 3,
+            looped: false,
+        };
+        assert_eq!(
+            contraption.beam_stats(Pos2::new(0, 1), Direction::East),
+            expected
+        );
+
+        // both beams of the first splitter merge at the second one
+        let contraption: Contraption = "/.-.\\\n../..\n\\.|./".parse()?;
+        let expected = BeamStats {
+            energized: 14,
+            looped: false,
+        };
+        assert_eq!(
+            contraption.beam_stats(Pos2::new(2, 0), Direction::South),
+            expected
+        );
+
+        Ok(())
+    }
 }
@@ -1,8 +1,11 @@
 use self::debug::HeatDebugger;
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use crate::common::{
     direction::Direction,
-    path_finder::{find_best_path, FingerprintItem, FingerprintSkipper, PathFinder},
+    helper::{parse_digit_grid, GridError},
+    path_finder::{
+        find_best_path, find_best_path_bounded, FingerprintItem, FingerprintSkipper, PathFinder,
+    },
     pos2::Pos2,
 };
 use itertools::Itertools;
@@ -30,6 +33,11 @@ impl DayTrait for Day {
         map.set_checker(HeatChecker::new(4, 10));
         Ok(map.best_path()?.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: HeatMap = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -170,6 +178,34 @@ struct HeatMap {
 }
 
 impl HeatMap {
+    fn new(map: Vec<Vec<u32>>) -> Result<Self, DayError> {
+        if map.is_empty() || map[0].is_empty() {
+            return Err(DayError::HeadMapMustNotBeEmpty);
+        }
+        if !map.iter().map(|row| row.len()).all_equal() {
+            return Err(DayError::HeatMapMustBeRectangle);
+        }
+        Ok(Self {
+            map,
+            checker: HeatChecker::new(1, 3),
+        })
+    }
+
+    /// parses a heat map whose cells are separated by whitespace,
+    /// so that they may have more than one digit
+    #[allow(dead_code)]
+    pub fn from_str_spaced(input: &str) -> Result<Self, DayError> {
+        let map: Vec<Vec<u32>> = input
+            .lines()
+            .map(|row| {
+                row.split_ascii_whitespace()
+                    .map(|c| c.parse())
+                    .try_collect()
+            })
+            .try_collect()?;
+        Self::new(map)
+    }
+
     pub fn set_checker(&mut self, checker: HeatChecker) {
         self.checker = checker;
     }
@@ -179,6 +215,15 @@ impl HeatMap {
             .map(|heat_flow| heat_flow.loss)
             .ok_or(DayError::NoBestPathFound)
     }
+
+    /// same as best_path, but prunes all flows that lose more heat
+    /// than the best finished one
+    #[allow(dead_code)]
+    pub fn best_path_bounded(self) -> Result<u32, DayError> {
+        find_best_path_bounded(self, |heat_flow| heat_flow.loss)
+            .map(|heat_flow| heat_flow.loss)
+            .ok_or(DayError::NoBestPathFound)
+    }
 }
 
 impl PathFinder for HeatMap {
@@ -251,24 +296,7 @@ impl FromStr for HeatMap {
     type Err = DayError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let map: Vec<Vec<u32>> = input
-            .lines()
-            .map(|row| {
-                row.chars()
-                    .map(|c| c.to_digit(10).ok_or(DayError::NoAsciiNumber(c)))
-                    .try_collect()
-            })
-            .try_collect()?;
-        if map.is_empty() || map[0].is_empty() {
-            return Err(DayError::HeadMapMustNotBeEmpty);
-        }
-        if !map.iter().map(|row| row.len()).all_equal() {
-            return Err(DayError::HeatMapMustBeRectangle);
-        }
-        Ok(Self {
-            map,
-            checker: HeatChecker::new(1, 3),
-        })
+        Self::new(parse_digit_grid(input)?)
     }
 }
 
@@ -286,6 +314,15 @@ enum DayError {
     NoBestPathFound,
 }
 
+impl From<GridError> for DayError {
+    fn from(value: GridError) -> Self {
+        match value {
+            GridError::NotADigit(c) => DayError::NoAsciiNumber(c),
+            GridError::NotARectangle => DayError::HeatMapMustBeRectangle,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -323,4 +360,33 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn bounded() -> UnitResult {
+        let day = Day {};
+        for name in ["example01.txt", "example02.txt"] {
+            let input = read_string(day.get_day_number(), name)?;
+            for checker in [HeatChecker::new(1, 3), HeatChecker::new(4, 10)] {
+                let mut map: HeatMap = input.parse()?;
+                map.set_checker(checker);
+                let expected = map.best_path()?;
+
+                let mut map: HeatMap = input.parse()?;
+                map.set_checker(checker);
+                assert_eq!(map.best_path_bounded()?, expected);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn spaced() -> UnitResult {
+        let input = "1 10 12\n1 25 1\n1  1 1\n";
+        let map = HeatMap::from_str_spaced(input)?;
+        assert_eq!(map.map[0], [1, 10, 12]);
+        assert_eq!(map.best_path()?, 4);
+
+        Ok(())
+    }
 }
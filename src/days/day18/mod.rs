@@ -1,4 +1,4 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use crate::common::{direction::Direction, pos2::Pos2, turn::Turn};
 use itertools::Itertools;
 use num_traits::Zero;
@@ -6,6 +6,14 @@ use std::num;
 
 const DAY_NUMBER: DayType = 18;
 
+/// the direction for each value of the last digit of a coded instruction
+const CODED_DIRECTIONS: [Direction; 4] = [
+    Direction::East,
+    Direction::South,
+    Direction::West,
+    Direction::North,
+];
+
 pub struct Day;
 
 impl DayTrait for Day {
@@ -24,6 +32,12 @@ impl DayTrait for Day {
         let steps = lagoon.pool_size();
         Ok(steps.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        Lagoon::from_simple(input)?;
+        Lagoon::from_coded(input)?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -38,8 +52,12 @@ enum DayError {
     DoesNotLoopBack,
     #[error("Loop must not be empty")]
     LoopMustBeAtLeast4,
+    #[error("Diagonal instructions are not supported: {0}")]
+    DiagonalNotSupported(String),
 }
 
+/// A single dig instruction. Only the four cardinal directions are
+/// supported, as pool_size relies on the trench being axis aligned
 #[derive(Debug)]
 struct Instruction {
     direction: Direction,
@@ -52,11 +70,18 @@ impl Instruction {
         let Some(direction) = parts.next() else {
             return Err(DayError::ParseError(input.to_owned()));
         };
-        let direction = match direction {
-            "U" => Direction::North,
-            "R" => Direction::East,
-            "D" => Direction::South,
-            "L" => Direction::West,
+        let Some(directions) = direction
+            .chars()
+            .map(Instruction::parse_direction)
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Err(DayError::ParseError(input.to_owned()));
+        };
+        let direction = match directions.as_slice() {
+            [direction] => *direction,
+            [fst, snd] if fst.is_perpendicular(snd) => {
+                return Err(DayError::DiagonalNotSupported(input.to_owned()))
+            }
             _ => return Err(DayError::ParseError(input.to_owned())),
         };
         let Some(steps) = parts.next() else {
@@ -68,24 +93,43 @@ impl Instruction {
         })
     }
 
+    fn parse_direction(direction: char) -> Option<Direction> {
+        match direction {
+            'U' => Some(Direction::North),
+            'R' => Some(Direction::East),
+            'D' => Some(Direction::South),
+            'L' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
     pub fn from_coded(input: &str) -> Result<Self, DayError> {
+        Self::from_coded_with(input, &CODED_DIRECTIONS)
+    }
+
+    /// decodes like from_coded, but with the given direction for each
+    /// value of the last digit
+    pub fn from_coded_with(input: &str, directions: &[Direction; 4]) -> Result<Self, DayError> {
         let Some((_, hex)) = input.split_once('#') else {
             return Err(DayError::ParseError(input.to_owned()));
         };
         let Some(color) = hex.strip_suffix(')') else {
             return Err(DayError::ParseError(input.to_owned()));
         };
-        let direction = match color.chars().nth(5) {
-            Some('0') => Direction::East,
-            Some('1') => Direction::South,
-            Some('2') => Direction::West,
-            Some('3') => Direction::North,
-            _ => return Err(DayError::ParseError(input.to_owned())),
-        };
-        let steps = color
+        let Some(digits) = color
             .chars()
-            .take(5)
-            .fold(0, |s, c| s * 16 + c.to_digit(16).unwrap() as i64);
+            .map(|c| c.to_digit(16))
+            .collect::<Option<Vec<_>>>()
+            .filter(|digits| digits.len() == 6)
+        else {
+            return Err(DayError::ParseError(input.to_owned()));
+        };
+        let Some(&direction) = directions.get(digits[5] as usize) else {
+            return Err(DayError::ParseError(input.to_owned()));
+        };
+        let steps = digits[..5]
+            .iter()
+            .fold(0, |s, digit| s * 16 + *digit as i64);
         Ok(Self { direction, steps })
     }
 
@@ -134,6 +178,16 @@ impl Lagoon {
         Self::new(input.lines().map(Instruction::from_coded).try_collect()?)
     }
 
+    #[allow(dead_code)]
+    pub fn from_coded_with(input: &str, directions: &[Direction; 4]) -> Result<Self, DayError> {
+        Self::new(
+            input
+                .lines()
+                .map(|line| Instruction::from_coded_with(line, directions))
+                .try_collect()?,
+        )
+    }
+
     fn does_loop_back(&self) -> bool {
         self.instructions
             .iter()
@@ -165,6 +219,38 @@ impl Lagoon {
         }
     }
 
+    #[allow(dead_code)]
+    pub fn vertices(&self) -> Vec<Pos2<i64>> {
+        self.instructions
+            .iter()
+            .scan(Pos2::zero(), |pos, instruction| {
+                *pos = instruction.walk(*pos);
+                Some(*pos)
+            })
+            .collect()
+    }
+
+    #[allow(dead_code)]
+    pub fn perimeter(&self) -> i64 {
+        self.instructions
+            .iter()
+            .map(|instruction| instruction.steps())
+            .sum()
+    }
+
+    #[allow(dead_code)]
+    pub fn area_shoelace(&self) -> i64 {
+        let double_area = self
+            .vertices()
+            .into_iter()
+            .circular_tuple_windows()
+            .map(|(curr, next)| curr.x() * next.y() - next.x() * curr.y())
+            .sum::<i64>()
+            .abs();
+        // Pick's theorem gives the inner points, the trench itself adds the boundary
+        (double_area + self.perimeter()) / 2 + 1
+    }
+
     pub fn pool_size(&self) -> i64 {
         let (area, bars) = self
             .instructions
@@ -341,4 +427,99 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn trench() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let steps: i64 = input
+            .lines()
+            .map(|line| Instruction::from_simple(line).map(|instruction| instruction.steps()))
+            .sum::<Result<_, _>>()?;
+
+        let lagoon = Lagoon::from_simple(&input)?;
+        assert_eq!(lagoon.perimeter(), steps);
+        assert_eq!(lagoon.perimeter(), 38);
+
+        let vertices = lagoon.vertices();
+        assert_eq!(vertices.len(), 14);
+        assert_eq!(vertices.last(), Some(&Pos2::zero()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn shoelace() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+
+        let lagoon = Lagoon::from_simple(&input)?;
+        assert_eq!(lagoon.area_shoelace(), lagoon.pool_size());
+
+        let lagoon = Lagoon::from_coded(&input)?;
+        assert_eq!(lagoon.area_shoelace(), lagoon.pool_size());
+
+        Ok(())
+    }
+
+    #[test]
+    fn other_encoding() -> UnitResult {
+        let swapped = [
+            Direction::North,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+        ];
+        let instruction = Instruction::from_coded_with("R 6 (#70c710)", &swapped)?;
+        assert_eq!(instruction.direction(), Direction::North);
+        assert_eq!(instruction.steps(), 461937);
+
+        // mirrored along the diagonal, the pool keeps its size
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let mirrored = [
+            Direction::South,
+            Direction::East,
+            Direction::North,
+            Direction::West,
+        ];
+        let lagoon = Lagoon::from_coded_with(&input, &mirrored)?;
+        assert_eq!(lagoon.pool_size(), 952408144115);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_color() {
+        assert!(matches!(
+            Instruction::from_coded("R 6 (#70c71)"),
+            Err(DayError::ParseError(_))
+        ));
+        assert!(matches!(
+            Instruction::from_coded("R 6 (#70c7100)"),
+            Err(DayError::ParseError(_))
+        ));
+        assert!(matches!(
+            Instruction::from_coded("R 6 (#70g710)"),
+            Err(DayError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn parse_diagonal() {
+        assert!(matches!(
+            Instruction::from_simple("UR 3 (#70c710)"),
+            Err(DayError::DiagonalNotSupported(_))
+        ));
+        assert!(matches!(
+            Lagoon::from_simple("R 2 (#70c710)\nDL 2 (#70c710)\nU 2 (#70c710)"),
+            Err(DayError::DiagonalNotSupported(_))
+        ));
+        for direction in ["UD", "RR", "URD", "UX", "x"] {
+            assert!(matches!(
+                Instruction::from_simple(&format!("{direction} 3 (#70c710)")),
+                Err(DayError::ParseError(_))
+            ));
+        }
+    }
 }
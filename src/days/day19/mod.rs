@@ -1,4 +1,4 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use std::{num, str::FromStr};
 
@@ -19,9 +19,14 @@ impl DayTrait for Day {
     fn part2(&self, input: &str) -> RResult {
         let system: System = input.try_into()?;
         Ok(system
-            .count_fitting(PartRange::splat(Range::new(1, 4_000)))
+            .count_fitting(PartRange::splat(Range::new(1, 4_000)))?
             .into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: System = input.try_into()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -32,6 +37,8 @@ enum DayError {
     ParseIntError(#[from] num::ParseIntError),
     #[error("Unknown Workflow: {0}")]
     UnknownWorkflow(String),
+    #[error("Too many parts to count")]
+    CountOverflow,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -143,7 +150,7 @@ impl<'a> TryFrom<&'a str> for Rule<'a> {
 }
 
 impl Rule<'_> {
-    pub fn apply(&self, part: &Part) -> Option<&Progress> {
+    pub fn apply(&self, part: &Part) -> Option<&Progress<'_>> {
         if self.condition.check(part) {
             Some(&self.progress)
         } else {
@@ -151,7 +158,10 @@ impl Rule<'_> {
         }
     }
 
-    fn apply_range(&self, range: PartRange) -> (Option<(PartRange, &Progress)>, Option<PartRange>) {
+    fn apply_range(
+        &self,
+        range: PartRange,
+    ) -> (Option<(PartRange, &Progress<'_>)>, Option<PartRange>) {
         let (this, next) = self.condition.check_range(range);
         (this.map(|range| (range, &self.progress)), next)
     }
@@ -179,7 +189,7 @@ impl<'a> TryFrom<&'a str> for Workflow<'a> {
 }
 
 impl Workflow<'_> {
-    pub fn is_accepted(&self, part: &Part) -> &Progress {
+    pub fn is_accepted(&self, part: &Part) -> &Progress<'_> {
         for rule in self.rules.iter() {
             if let Some(progress) = rule.apply(part) {
                 return progress;
@@ -230,29 +240,60 @@ impl Workflows<'_> {
         }
     }
 
-    fn count_by_workflow(&self, mut range: PartRange, name: &str) -> usize {
-        let mut count = 0;
-        let wf = self.find(name).unwrap();
+    /// counts all parts in range that end up accepted or rejected,
+    /// depending on the parameter accepted
+    fn count_by_workflow(
+        &self,
+        mut range: PartRange,
+        name: &str,
+        accepted: bool,
+    ) -> Result<usize, DayError> {
+        let mut count: usize = 0;
+        let wf = self.find(name)?;
         for rule in wf.rules.iter() {
             let (this, next) = rule.apply_range(range);
             if let Some((range, progress)) = this {
-                match progress {
-                    Progress::Reject => {}
-                    Progress::Accept => count += range.count(),
+                let found = match progress {
+                    Progress::Reject if !accepted => range.checked_count(),
+                    Progress::Accept if accepted => range.checked_count(),
+                    Progress::Reject | Progress::Accept => Some(0),
                     Progress::Continue(name) => {
-                        count += self.count_by_workflow(range, name);
+                        Some(self.count_by_workflow(range, name, accepted)?)
                     }
-                }
+                };
+                count = found
+                    .and_then(|found| count.checked_add(found))
+                    .ok_or(DayError::CountOverflow)?;
             }
             if let Some(next_range) = next {
                 range = next_range;
             }
         }
-        count
+        Ok(count)
+    }
+
+    pub fn count_accepted(&self, range: PartRange) -> Result<usize, DayError> {
+        self.count_by_workflow(range, "in", true)
     }
 
-    pub fn count_accepted(&self, range: PartRange) -> usize {
-        self.count_by_workflow(range, "in")
+    #[allow(dead_code)]
+    pub fn count_rejected(&self, range: PartRange) -> Result<usize, DayError> {
+        self.count_by_workflow(range, "in", false)
+    }
+
+    /// the condition letting the fewest of all possible parts pass,
+    /// together with the name of its workflow
+    #[allow(dead_code)]
+    pub fn tightest_constraint(&self) -> Option<(&str, &Condition)> {
+        let range = PartRange::splat(Range::new(1, 4_000));
+        self.workflows
+            .iter()
+            .flat_map(|wf| wf.rules.iter().map(move |rule| (wf.name, &rule.condition)))
+            .filter(|(_, condition)| !matches!(condition, Condition::Always))
+            .min_by_key(|(_, condition)| {
+                let (passing, _) = condition.check_range(range);
+                passing.map_or(0, |passing| passing.count())
+            })
     }
 }
 
@@ -290,6 +331,22 @@ impl Range {
         self.max - self.min + 1
     }
 
+    pub fn checked_count(&self) -> Option<usize> {
+        (self.max - self.min).checked_add(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min > self.max
+    }
+
+    pub fn intersect(self, other: &Range) -> Option<Self> {
+        let range = Self {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        };
+        (!range.is_empty()).then_some(range)
+    }
+
     pub fn set_min(mut self, min: usize) -> Option<Self> {
         self.min = self.min.max(min);
         if self.min > self.max {
@@ -383,9 +440,33 @@ impl PartRange {
         }
     }
 
+    #[allow(dead_code)]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty() || self.1.is_empty() || self.2.is_empty() || self.3.is_empty()
+    }
+
+    #[allow(dead_code)]
+    fn intersect(self, other: &PartRange) -> Option<Self> {
+        Some(Self(
+            self.0.intersect(&other.0)?,
+            self.1.intersect(&other.1)?,
+            self.2.intersect(&other.2)?,
+            self.3.intersect(&other.3)?,
+        ))
+    }
+
     fn count(&self) -> usize {
         self.0.count() * self.1.count() * self.2.count() * self.3.count()
     }
+
+    /// the number of parts in the range, None if it does not fit into usize
+    fn checked_count(&self) -> Option<usize> {
+        [self.1, self.2, self.3]
+            .iter()
+            .try_fold(self.0.checked_count()?, |count, range| {
+                count.checked_mul(range.checked_count()?)
+            })
+    }
 }
 
 fn get_pair(s: &str) -> Result<(&str, &str), DayError> {
@@ -425,11 +506,23 @@ impl<'a> TryFrom<&'a str> for System<'a> {
     type Error = DayError;
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        // parts start with a '{', workflows with their name,
+        // so we accept the blocks in either order
         let mut lines = value.lines();
-        let workflows = Workflows::create(&mut lines)?;
-        let _ = lines.next();
-        let parts = lines.map(|p| p.parse()).try_collect()?;
-        Ok(Self { workflows, parts })
+        if value.starts_with('{') {
+            let parts = lines
+                .take_while_ref(|line| !line.is_empty())
+                .map(|p| p.parse())
+                .try_collect()?;
+            let _ = lines.next();
+            let workflows = Workflows::create(&mut lines)?;
+            Ok(Self { workflows, parts })
+        } else {
+            let workflows = Workflows::create(&mut lines)?;
+            let _ = lines.next();
+            let parts = lines.map(|p| p.parse()).try_collect()?;
+            Ok(Self { workflows, parts })
+        }
     }
 }
 
@@ -442,7 +535,7 @@ impl System<'_> {
             .sum()
     }
 
-    pub fn count_fitting(&self, range: PartRange) -> usize {
+    pub fn count_fitting(&self, range: PartRange) -> Result<usize, DayError> {
         self.workflows.count_accepted(range)
     }
 }
@@ -532,4 +625,95 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn accepted_and_rejected() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let system: System = input.as_str().try_into()?;
+
+        let range = PartRange::splat(Range::new(1, 4_000));
+        let accepted = system.workflows.count_accepted(range)?;
+        let rejected = system.workflows.count_rejected(range)?;
+        assert_eq!(accepted, 167409079868000);
+        assert_eq!(accepted + rejected, range.count());
+        assert_eq!(range.count(), 4_000usize.pow(4));
+
+        Ok(())
+    }
+
+    #[test]
+    fn checked_count() {
+        let range = PartRange::splat(Range::new(1, 4_000));
+        assert_eq!(range.checked_count(), Some(256_000_000_000_000));
+        assert_eq!(range.checked_count(), Some(range.count()));
+
+        let huge = PartRange::splat(Range::new(0, 1 << 16));
+        assert_eq!(huge.checked_count(), None);
+        assert_eq!(Range::new(0, usize::MAX).checked_count(), None);
+    }
+
+    #[test]
+    fn tightest_constraint() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let system: System = input.as_str().try_into()?;
+
+        assert_eq!(
+            system.workflows.tightest_constraint(),
+            Some(("rfg", &Condition::LowerThan(Param::S, 537)))
+        );
+
+        let workflows = Workflows::create(&mut "in{A}".lines())?;
+        assert_eq!(workflows.tightest_constraint(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_reversed() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let Some((workflows, parts)) = input.split_once("\n\n") else {
+            panic!("No empty line in example");
+        };
+        let reversed = format!("{}\n\n{}", parts.trim_end(), workflows);
+
+        let system: System = input.as_str().try_into()?;
+        let reversed_system: System = reversed.as_str().try_into()?;
+        assert_eq!(reversed_system.workflows.len(), 11);
+        assert_eq!(reversed_system.parts.len(), 5);
+        assert_eq!(reversed_system.value(), system.value());
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_ranges() {
+        let fst = PartRange(
+            Range::new(1, 100),
+            Range::new(1, 4_000),
+            Range::new(50, 60),
+            Range::new(10, 20),
+        );
+        let snd = PartRange::splat(Range::new(20, 80));
+        let Some(both) = fst.intersect(&snd) else {
+            panic!("Ranges should overlap");
+        };
+        assert!(!both.is_empty());
+        assert_eq!(both.count(), 61 * 61 * 11);
+
+        let disjoint = PartRange::splat(Range::new(21, 40));
+        assert!(fst.intersect(&disjoint).is_none());
+    }
+
+    #[test]
+    fn parse_only() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        day.parse_only(&input)?;
+        assert!(day.parse_only("in{x<5:A,R}\n\n{x=1,m=x,a=1,s=1}").is_err());
+
+        Ok(())
+    }
 }
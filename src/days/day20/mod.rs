@@ -1,9 +1,9 @@
-use super::{DayTrait, DayType, RResult};
-use crate::common::math::lcm;
+use super::{DayTrait, DayType, RResult, UnitResult};
+use crate::common::{helper::lines_no_comments, math::lcm};
 use itertools::Itertools;
 use std::{
     cell::{Cell, RefCell},
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     fmt::Display,
     num,
 };
@@ -25,9 +25,14 @@ impl DayTrait for Day {
 
     fn part2(&self, input: &str) -> RResult {
         let config: Configuration = input.try_into()?;
-        let pushes = ComplexSolver::solve(config)?;
+        let pushes = ComplexSolver::solve(config, TARGET)?;
         Ok(pushes.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Configuration = input.try_into()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -40,6 +45,8 @@ enum DayError {
     DestinationsMustNotBeEmpty(String),
     #[error("No broadcaster found")]
     NoBroadcaster,
+    #[error("Target can not be reached: {0}")]
+    UnreachableTarget(String),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -59,6 +66,7 @@ impl Display for Pulse {
 
 const BUTTON: &str = "button";
 const BROADCASTER: &str = "broadcaster";
+const TARGET: &str = "rx";
 
 #[derive(Debug, Clone)]
 struct Relay<'a> {
@@ -143,10 +151,23 @@ impl Conjunction<'_> {
         &self.destinations
     }
 
+    fn forget_sources(&self) {
+        self.prev.borrow_mut().clear();
+    }
+
     fn is_at_start_state(&self) -> bool {
         let prev = self.prev.borrow();
         prev.values().all(|p| matches!(p, Pulse::Low))
     }
+
+    /// the remembered pulses, ordered by their source
+    fn state(&self) -> Vec<bool> {
+        let prev = self.prev.borrow();
+        prev.iter()
+            .sorted_by_key(|(source, _)| *source)
+            .map(|(_, pulse)| matches!(pulse, Pulse::High))
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -198,10 +219,6 @@ impl<'a> Module<'a> {
             }))
         }
     }
-
-    fn is_flipflop(&self) -> bool {
-        matches!(self, Module::FlipFlop(_))
-    }
 }
 
 impl<'a> Module<'a> {
@@ -222,6 +239,15 @@ impl<'a> Module<'a> {
         }
     }
 
+    /// a copy of this module that does not remember any of its sources
+    fn detached(&self) -> Module<'a> {
+        let module = self.clone();
+        if let Module::Conjunction(m) = &module {
+            m.forget_sources();
+        }
+        module
+    }
+
     #[inline]
     fn will_work_on_pulse(&self, pulse: Pulse) -> bool {
         match self {
@@ -247,6 +273,14 @@ impl<'a> Module<'a> {
             Module::Conjunction(m) => m.is_at_start_state(),
         }
     }
+
+    fn state(&self) -> Vec<bool> {
+        match self {
+            Module::Relay(_) => vec![],
+            Module::FlipFlop(m) => vec![m.is_on.get()],
+            Module::Conjunction(m) => m.state(),
+        }
+    }
 }
 
 impl<'a> Module<'a> {
@@ -314,7 +348,7 @@ impl<'a> Configuration<'a> {
         let mut high = 0;
         let mut low = 0;
         for round in 1..=max_round {
-            let (next_low, next_high) = self.press_button(|_| {});
+            let (next_low, next_high) = self.press_button(|_, _| {});
             high += next_high;
             low += next_low;
             if self.is_at_start() {
@@ -324,9 +358,11 @@ impl<'a> Configuration<'a> {
         (max_round, low, high)
     }
 
+    /// presses the button once, inform_receiver gets to know about
+    /// every pulse sent to any destination
     pub fn press_button<F>(&mut self, inform_receiver: F) -> (usize, usize)
     where
-        F: Fn(Pulse),
+        F: Fn(&str, Pulse),
     {
         let mut low = 1;
         let mut high = 0;
@@ -343,12 +379,11 @@ impl<'a> Configuration<'a> {
                     Pulse::High => high += 1,
                     Pulse::Low => low += 1,
                 }
+                inform_receiver(dest_name, pulse);
                 if let Some(dest_module) = self.find(dest_name) {
                     if dest_module.will_work_on_pulse(pulse) {
                         queue.push_back((dest_name, module_name, pulse));
                     }
-                } else {
-                    inform_receiver(pulse)
                 }
             }
         }
@@ -359,19 +394,67 @@ impl<'a> Configuration<'a> {
         self.modules.iter().all(|m| m.is_at_start_state())
     }
 
-    fn count_pushes(&mut self) -> usize {
-        for p in 1.. {
+    /// everything the modules remember. Pressing the button in the same
+    /// state always has the same outcome
+    fn state(&self) -> Vec<bool> {
+        self.modules.iter().flat_map(|m| m.state()).collect()
+    }
+
+    #[allow(dead_code)]
+    pub fn to_dot(&self) -> String {
+        let modules = self.modules.iter().filter(|m| m.name() != BUTTON);
+        let nodes = modules.clone().map(|module| {
+            let (label, shape) = match module {
+                Module::Relay(m) => (m.name().to_owned(), "doubleoctagon"),
+                Module::FlipFlop(m) => (format!("%{}", m.name()), "box"),
+                Module::Conjunction(m) => (format!("&{}", m.name()), "diamond"),
+            };
+            format!(
+                "    {} [label=\"{}\", shape={}];",
+                module.name(),
+                label,
+                shape
+            )
+        });
+        let edges = modules.flat_map(|module| {
+            module
+                .get_destinations()
+                .iter()
+                .map(move |dest| format!("    {} -> {};", module.name(), dest))
+        });
+        std::iter::once("digraph modules {".to_owned())
+            .chain(nodes)
+            .chain(edges)
+            .chain(std::iter::once("}".to_owned()))
+            .join("\n")
+    }
+
+    /// presses the button until stop is true for any of the sent pulses.
+    /// None if the modules return to an earlier state before that, as
+    /// stop will then never be true
+    pub fn press_until<F>(&mut self, stop: F) -> Option<usize>
+    where
+        F: Fn(&str, Pulse) -> bool,
+    {
+        let mut seen = HashSet::new();
+        let mut presses = 0;
+        while seen.insert(self.state()) {
+            presses += 1;
             let do_continue = Cell::new(true);
-            self.press_button(|pulse| {
-                if matches!(pulse, Pulse::Low) {
+            self.press_button(|dest_name, pulse| {
+                if stop(dest_name, pulse) {
                     do_continue.set(false);
                 }
             });
             if !do_continue.get() {
-                return p;
+                return Some(presses);
             }
         }
-        unreachable!()
+        None
+    }
+
+    fn count_pushes(&mut self, target: &str) -> Option<usize> {
+        self.press_until(|dest_name, pulse| dest_name == target && matches!(pulse, Pulse::Low))
     }
 }
 
@@ -379,9 +462,7 @@ impl<'a> TryFrom<&'a str> for Configuration<'a> {
     type Error = DayError;
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let modules = value
-            .lines()
-            .filter(|line| !line.starts_with('#'))
+        let modules = lines_no_comments(value, "#")
             .map(Configuration::create_module)
             .chain(std::iter::once(Configuration::create_button()))
             .try_collect()?;
@@ -394,7 +475,10 @@ struct ComplexSolver<'a> {
 }
 
 impl<'a> ComplexSolver<'a> {
-    pub fn solve(configuration: Configuration<'a>) -> Result<usize, DayError> {
+    /// counts the button presses until target receives a low pulse. Every
+    /// output of the broadcaster is taken as an independent sub network,
+    /// the results of those leading to target are combined
+    pub fn solve(configuration: Configuration<'a>, target: &'a str) -> Result<usize, DayError> {
         let solver = ComplexSolver { configuration };
         let bc = solver
             .configuration
@@ -402,35 +486,45 @@ impl<'a> ComplexSolver<'a> {
             .ok_or(DayError::NoBroadcaster)?;
 
         let mut rounds = 1;
+        let mut reached = false;
         for split in bc.get_destinations() {
-            let sub_modules = solver.collect(split);
+            let sub_modules = solver.collect(split, target);
+            if !sub_modules
+                .iter()
+                .any(|module| module.get_destinations().contains(&target))
+            {
+                continue;
+            }
+            reached = true;
             let mut sub_config = Configuration::new(sub_modules)?;
-            let pushes = sub_config.count_pushes();
+            let pushes = sub_config
+                .count_pushes(target)
+                .ok_or_else(|| DayError::UnreachableTarget(target.to_owned()))?;
             rounds = lcm(rounds, pushes);
         }
+        if !reached {
+            return Err(DayError::UnreachableTarget(target.to_owned()));
+        }
         Ok(rounds)
     }
 
-    fn collect(&'a self, start: &'a str) -> Vec<Module<'a>> {
+    fn collect(&'a self, start: &'a str, target: &'a str) -> Vec<Module<'a>> {
         let mut queue = vec![start];
         let mut names = vec![];
         while let Some(name) = queue.pop() {
-            if names.contains(&name) {
+            if name == target || names.contains(&name) {
                 continue;
             }
             names.push(name);
             let Some(module) = self.configuration.find(name) else {
                 continue;
             };
-            if !module.is_flipflop() {
-                continue;
-            }
             queue.extend(module.get_destinations())
         }
 
         names
             .into_iter()
-            .map(|name| self.configuration.find(name).cloned())
+            .map(|name| self.configuration.find(name).map(Module::detached))
             .chain(std::iter::once(
                 Module::relay(BROADCASTER, vec![start]).ok(),
             ))
@@ -472,7 +566,7 @@ mod test {
         let input = read_string(day.get_day_number(), "example01.txt")?;
 
         let mut config: Configuration = input.as_str().try_into()?;
-        assert_eq!(config.press_button(|_| {}), (8, 4));
+        assert_eq!(config.press_button(|_, _| {}), (8, 4));
 
         Ok(())
     }
@@ -509,4 +603,124 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn to_dot() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+
+        let config: Configuration = input.as_str().try_into()?;
+        let dot = config.to_dot();
+        let lines = dot.lines().collect_vec();
+        assert_eq!(lines.first(), Some(&"digraph modules {"));
+        assert_eq!(lines.last(), Some(&"}"));
+        assert!(lines.contains(&"    broadcaster [label=\"broadcaster\", shape=doubleoctagon];"));
+        assert!(lines.contains(&"    a [label=\"%a\", shape=box];"));
+        assert!(lines.contains(&"    inv [label=\"&inv\", shape=diamond];"));
+        assert!(lines.contains(&"    broadcaster -> b;"));
+        assert!(lines.contains(&"    c -> inv;"));
+        assert!(lines.contains(&"    inv -> a;"));
+        assert!(!dot.contains(BUTTON));
+
+        Ok(())
+    }
+
+    #[test]
+    fn press_until() -> UnitResult {
+        let input = "broadcaster -> a\n%a -> b\n%b -> out";
+
+        let mut config: Configuration = input.try_into()?;
+        assert_eq!(
+            config.press_until(|dest, pulse| dest == "b" && matches!(pulse, Pulse::High)),
+            Some(1)
+        );
+
+        let mut config: Configuration = input.try_into()?;
+        assert_eq!(
+            config.press_until(|dest, pulse| dest == "out" && matches!(pulse, Pulse::High)),
+            Some(2)
+        );
+
+        let mut config: Configuration = input.try_into()?;
+        assert_eq!(
+            config.press_until(|dest, pulse| dest == "out" && matches!(pulse, Pulse::Low)),
+            Some(4)
+        );
+
+        let mut config: Configuration = input.try_into()?;
+        assert_eq!(config.press_until(|dest, _| dest == "nowhere"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_target() -> UnitResult {
+        let input = "broadcaster -> a, b
+%a -> ca
+&ca -> t
+%b -> b2
+%b2 -> cb
+&cb -> t
+&t -> rx";
+
+        let config: Configuration = input.try_into()?;
+        assert_eq!(ComplexSolver::solve(config, "rx")?, 4);
+
+        let config: Configuration = input.try_into()?;
+        assert_eq!(ComplexSolver::solve(config, "t")?, 2);
+
+        let config: Configuration = input.try_into()?;
+        assert_eq!(ComplexSolver::solve(config, "ca")?, 2);
+
+        let config: Configuration = input.try_into()?;
+        assert!(matches!(
+            ComplexSolver::solve(config, "nowhere"),
+            Err(DayError::UnreachableTarget(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn never_low() -> UnitResult {
+        // the conjunction only ever remembers a low pulse, so it always sends high
+        let input = "broadcaster -> inv\n&inv -> rx";
+
+        let mut config: Configuration = input.try_into()?;
+        assert_eq!(config.count_pushes("rx"), None);
+
+        let config: Configuration = input.try_into()?;
+        assert!(matches!(
+            ComplexSolver::solve(config, "rx"),
+            Err(DayError::UnreachableTarget(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn counters() -> UnitResult {
+        // two binary counters, resetting after 5 and 7 presses, like in the puzzle input
+        let input = "broadcaster -> a0, b0
+%a0 -> a1, ha
+%a1 -> a2
+%a2 -> ha
+&ha -> a0, a1, ia
+&ia -> fin
+%b0 -> b1, hb
+%b1 -> b2, hb
+%b2 -> hb
+&hb -> b0, ib
+&ib -> fin
+&fin -> rx";
+
+        let mut config: Configuration = input.try_into()?;
+        let expected = config.count_pushes("rx");
+        assert_eq!(expected, Some(35));
+
+        let config: Configuration = input.try_into()?;
+        assert_eq!(ComplexSolver::solve(config, "rx").ok(), expected);
+
+        Ok(())
+    }
 }
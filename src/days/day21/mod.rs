@@ -1,7 +1,11 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use crate::common::{direction::Direction, pos2::Pos2};
 use itertools::Itertools;
-use std::{collections::HashSet, fmt::Display, num, str::FromStr};
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+    str::FromStr,
+};
 
 const DAY_NUMBER: DayType = 21;
 
@@ -23,14 +27,21 @@ impl DayTrait for Day {
         let plots = map.do_many_steps()?;
         Ok(plots.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: GardenMap = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 enum DayError {
-    #[error("Not a valid description: {0}")]
-    ParseError(String),
-    #[error("Not an Int")]
-    ParseIntError(#[from] num::ParseIntError),
+    #[error("The input must start with the step counts as `few/many`")]
+    MissingStepsHeader,
+    #[error("Not a valid steps header: {0}")]
+    MalformedStepsHeader(String),
+    #[error("There is no map after the steps header")]
+    MissingMap,
     #[error("Unknown PLot: {0}")]
     UnknownPlot(char),
     #[error("There must be exactly one start")]
@@ -98,14 +109,16 @@ impl FromStr for GardenMap {
     type Err = DayError;
 
     fn from_str(lines: &str) -> Result<Self, Self::Err> {
-        let Some((steps, map)) = lines.split_once('\n') else {
-            return Err(DayError::ParseError(lines.to_owned()));
-        };
+        let (steps, map) = lines.split_once('\n').unwrap_or((lines, ""));
         let Some((steps1, steps2)) = steps.split_once('/') else {
-            return Err(DayError::ParseError(steps.to_owned()));
+            return Err(DayError::MissingStepsHeader);
         };
-        let few_steps = steps1.trim().parse()?;
-        let many_steps = steps2.trim().parse()?;
+        let malformed = |_| DayError::MalformedStepsHeader(steps.to_owned());
+        let few_steps = steps1.trim().parse().map_err(malformed)?;
+        let many_steps = steps2.trim().parse().map_err(malformed)?;
+        if map.trim().is_empty() {
+            return Err(DayError::MissingMap);
+        }
 
         let plots: Vec<Vec<Plot>> = map
             .lines()
@@ -167,6 +180,32 @@ impl GardenMap {
         next_positions
     }
 
+    /// counts the plots reachable from start within one tile in an even
+    /// and in an odd number of steps, as these alternate forever
+    #[allow(dead_code)]
+    pub fn parity_counts(&self, start: Pos2<usize>) -> (usize, usize) {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        seen.insert(start);
+        queue.push_back((start, 0));
+        let mut counts = (0, 0);
+        while let Some((pos, steps)) = queue.pop_front() {
+            if steps % 2 == 0 {
+                counts.0 += 1;
+            } else {
+                counts.1 += 1;
+            }
+            for dir in Direction::iter() {
+                if let Some((p, pl)) = pos.safe_matrix_add_and_get(&self.plots, dir) {
+                    if pl.is_garden() && seen.insert(p) {
+                        queue.push_back((p, steps + 1));
+                    }
+                }
+            }
+        }
+        counts
+    }
+
     fn do_many_steps(&self) -> Result<usize, DayError> {
         self.calc_many_steps(self.many_steps)
     }
@@ -272,9 +311,13 @@ mod test {
     fn test_part2() -> UnitResult {
         let day = Day {};
         let input = read_string(day.get_day_number(), "example01.txt")?;
-        let expected = ResultType::Nothing;
-        let result = day.part2(&input)?;
-        assert_eq!(result, expected);
+        // the example has rocks in the row and column of the start, so the
+        // shortcut for many steps does not apply
+        let result = day.part2(&input);
+        assert!(matches!(
+            result.map_err(|err| err.downcast::<DayError>()),
+            Err(Ok(DayError::AlgorithmDoesNotWork))
+        ));
 
         Ok(())
     }
@@ -356,4 +399,41 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn parity() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example02.txt")?;
+        let map: GardenMap = input.parse()?;
+
+        let (even, odd) = map.parity_counts(map.start);
+        assert_eq!((even, odd), (49, 53));
+        let steps = map.plots.len() * 2;
+        assert_eq!(map.calc_steps_from_single(steps, map.start).len(), even);
+        assert_eq!(map.calc_steps_from_single(steps + 1, map.start).len(), odd);
+
+        Ok(())
+    }
+
+    #[test]
+    fn steps_header() {
+        let map = "...\n.S.\n...\n";
+        assert!(matches!(
+            map.parse::<GardenMap>(),
+            Err(DayError::MissingStepsHeader)
+        ));
+        assert!(matches!(
+            format!("6/x\n{map}").parse::<GardenMap>(),
+            Err(DayError::MalformedStepsHeader(header)) if header == "6/x"
+        ));
+        assert!(matches!(
+            "6/5000\n".parse::<GardenMap>(),
+            Err(DayError::MissingMap)
+        ));
+        assert!(matches!(
+            "6/5000".parse::<GardenMap>(),
+            Err(DayError::MissingMap)
+        ));
+        assert!(format!("6/5000\n{map}").parse::<GardenMap>().is_ok());
+    }
 }
@@ -1,5 +1,5 @@
-use super::{DayTrait, DayType, RResult};
-use crate::common::{pos2::Pos2, pos3::Pos3};
+use super::{DayTrait, DayType, RResult, UnitResult};
+use crate::common::{helper::dedup_keep_order, pos2::Pos2, pos3::Pos3};
 use itertools::Itertools;
 use std::{collections::HashSet, num, str::FromStr};
 
@@ -25,6 +25,11 @@ impl DayTrait for Day {
 
         Ok(settled.count_falling().into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Pile = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -117,7 +122,7 @@ impl Brick {
                 Ok(Self::create(
                     second.project_xy(),
                     second.z(),
-                    first.x() - second.x() + 1,
+                    first.y() - second.y() + 1,
                     Direction::Y,
                 ))
             }
@@ -133,7 +138,7 @@ impl Brick {
                 second.project_xy(),
                 second.z(),
                 first.z() - second.z() + 1,
-                Direction::X,
+                Direction::Z,
             ))
         }
     }
@@ -293,6 +298,7 @@ impl FromStr for Pile {
 
 struct SettledBrick {
     brick: Brick,
+    original_z: usize,
     foundation: Vec<usize>,
     supported: Vec<usize>,
 }
@@ -320,9 +326,7 @@ impl SettledPile {
                                     min_z = z;
                                     foundation.clear();
                                 }
-                                if !foundation.contains(&lower_pos) {
-                                    foundation.push(lower_pos);
-                                }
+                                foundation.push(lower_pos);
                                 found = true;
                             }
                         }
@@ -332,12 +336,14 @@ impl SettledPile {
                     }
                 }
             }
+            let foundation = dedup_keep_order(&foundation);
             let index = bricks.len();
             for idx in foundation.iter() {
                 bricks[*idx].supported.push(index);
             }
             let new_brick = SettledBrick {
                 brick: brick.set_z_pos(min_z + 1),
+                original_z: brick.z_pos,
                 foundation,
                 supported: vec![],
             };
@@ -346,6 +352,31 @@ impl SettledPile {
         Self { bricks }
     }
 
+    #[allow(dead_code)]
+    pub fn support_graph(&self) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+        self.bricks
+            .iter()
+            .map(|brick| (brick.foundation.clone(), brick.supported.clone()))
+            .unzip()
+    }
+
+    /// how far each brick fell while settling
+    #[allow(dead_code)]
+    pub fn drop_distances(&self) -> Vec<usize> {
+        self.bricks
+            .iter()
+            .map(|brick| brick.original_z - brick.brick.z_pos)
+            .collect()
+    }
+
+    /// every brick either rests on the floor or on some other brick
+    #[allow(dead_code)]
+    pub fn is_stable(&self) -> bool {
+        self.bricks
+            .iter()
+            .all(|brick| brick.brick.z_pos == 1 || !brick.foundation.is_empty())
+    }
+
     pub fn disintegratable_count(&self) -> usize {
         self.bricks.len() - self.stabelizers().len()
     }
@@ -435,6 +466,21 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn parse_reversed() -> UnitResult {
+        let brick: Brick = "1,2,1~1,0,1".parse()?;
+        assert_eq!(brick.direction, Direction::Y);
+        assert_eq!(brick.start, Pos2::new(1, 0));
+        assert_eq!(brick.length, 3);
+
+        let brick: Brick = "4,1,1~2,1,1".parse()?;
+        assert_eq!(brick.direction, Direction::X);
+        assert_eq!(brick.start, Pos2::new(2, 1));
+        assert_eq!(brick.length, 3);
+
+        Ok(())
+    }
+
     #[test]
     fn parse_vert() -> UnitResult {
         let input = "1,1,9~1,1,8";
@@ -474,4 +520,50 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn support_graph() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let pile: Pile = input.parse()?;
+        let settled = SettledPile::create(pile);
+        let (foundations, supports) = settled.support_graph();
+        assert_eq!(foundations.len(), 7);
+        assert!(foundations[0].is_empty());
+        assert_eq!(foundations[1], [0]);
+        assert_eq!(foundations[3].iter().sorted().collect_vec(), [&1, &2]);
+        assert_eq!(supports[0].iter().sorted().collect_vec(), [&1, &2]);
+        assert_eq!(supports[5], [6]);
+        assert!(supports[6].is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn drop_distances() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let pile: Pile = input.parse()?;
+        let settled = SettledPile::create(pile);
+
+        let distances = settled.drop_distances();
+        assert_eq!(distances.len(), 7);
+        assert_eq!(distances.iter().sum::<usize>(), 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn stable() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let pile: Pile = input.parse()?;
+        let mut settled = SettledPile::create(pile);
+        assert!(settled.is_stable());
+
+        settled.bricks[3].foundation.clear();
+        assert!(!settled.is_stable());
+
+        Ok(())
+    }
 }
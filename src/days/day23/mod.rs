@@ -1,7 +1,11 @@
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use crate::common::{direction::Direction, pos2::Pos2};
 use itertools::Itertools;
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 const DAY_NUMBER: DayType = 23;
 
@@ -22,6 +26,11 @@ impl DayTrait for Day {
         map.remove_slopes();
         Ok(map.go_on_hike()?.into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: ForestMap = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -34,6 +43,8 @@ enum DayError {
     MapMustBeRectangle,
     #[error("Map must have exactly one start and finish")]
     MustHaveExactOneStartAndEnd,
+    #[error("Position is not on a path: {0}")]
+    NotOnPath(Pos2<usize>),
     #[error("Map must be surrounded by forest")]
     MustBeSurroundedByForrest,
     #[error("No path found")]
@@ -102,12 +113,7 @@ struct ForestMap {
 
 impl ForestMap {
     pub fn new(map: Vec<Vec<Tile>>) -> Result<Self, DayError> {
-        if map.is_empty() || map[0].is_empty() {
-            return Err(DayError::MapMustNotBeEmpty);
-        }
-        if !map.iter().map(|row| row.len()).all_equal() {
-            return Err(DayError::MapMustBeRectangle);
-        }
+        Self::check_shape(&map)?;
         let start = map[0]
             .iter()
             .enumerate()
@@ -122,6 +128,39 @@ impl ForestMap {
             .exactly_one()
             .map_err(|_| DayError::MustHaveExactOneStartAndEnd)
             .map(|(x, _)| Pos2::new(x, map.len() - 1))?;
+        Self::create(map, start, finish)
+    }
+
+    #[allow(dead_code)]
+    pub fn with_start_and_finish(
+        map: Vec<Vec<Tile>>,
+        start: Pos2<usize>,
+        finish: Pos2<usize>,
+    ) -> Result<Self, DayError> {
+        Self::check_shape(&map)?;
+        for pos in [start, finish] {
+            if !matches!(pos.safe_matrix_get(&map), Some(Tile::Path)) {
+                return Err(DayError::NotOnPath(pos));
+            }
+        }
+        Self::create(map, start, finish)
+    }
+
+    fn check_shape(map: &[Vec<Tile>]) -> Result<(), DayError> {
+        if map.is_empty() || map[0].is_empty() {
+            return Err(DayError::MapMustNotBeEmpty);
+        }
+        if !map.iter().map(|row| row.len()).all_equal() {
+            return Err(DayError::MapMustBeRectangle);
+        }
+        Ok(())
+    }
+
+    fn create(
+        map: Vec<Vec<Tile>>,
+        start: Pos2<usize>,
+        finish: Pos2<usize>,
+    ) -> Result<Self, DayError> {
         if map.iter().any(|row| {
             !matches!(row[0], Tile::Forest) || !matches!(row[row.len() - 1], Tile::Forest)
         }) {
@@ -150,13 +189,9 @@ impl ForestMap {
         match (self.slippery_slopes, here.safe_matrix_get(&self.map)) {
             (false, Some(Tile::Slope(_))) | (_, Some(Tile::Path)) => Direction::iter()
                 .filter_map(|dir| {
-                    if let Some((next, tile)) = here.safe_matrix_add_and_get(&self.map, dir)
-                        && !matches!(tile, Tile::Forest)
-                    {
-                        Some(Step::create(here, dir, next))
-                    } else {
-                        None
-                    }
+                    here.safe_matrix_add_and_get(&self.map, dir)
+                        .filter(|(_, tile)| !matches!(tile, Tile::Forest))
+                        .map(|(next, _)| Step::create(here, dir, next))
                 })
                 .collect_vec(),
             (true, Some(Tile::Slope(dir))) => {
@@ -223,14 +258,28 @@ impl ForestMap {
     }
 
     pub fn go_on_hike(&self) -> Result<usize, DayError> {
+        self.longest_path_count().map(|(max, _)| max)
+    }
+
+    /// the length of the longest hike and the number of
+    /// distinct hikes having that length
+    pub fn longest_path_count(&self) -> Result<(usize, usize), DayError> {
         let connections = self.find_paths()?;
         let path = (vec![self.start], 0);
         let mut queue = vec![path];
         let mut max = 0;
+        let mut count = 0;
         while let Some((path, steps)) = queue.pop() {
             let current = path.last().unwrap();
             if current == &self.finish {
-                max = max.max(steps);
+                match steps.cmp(&max) {
+                    Ordering::Greater => {
+                        max = steps;
+                        count = 1;
+                    }
+                    Ordering::Equal => count += 1,
+                    Ordering::Less => {}
+                }
                 continue;
             }
             let Some(following) = connections.get(current) else {
@@ -247,7 +296,7 @@ impl ForestMap {
         if max == 0 {
             return Err(DayError::NoPathFound);
         };
-        Ok(max)
+        Ok((max, count))
     }
 
     pub fn find_paths(&self) -> Result<HashMap<Pos2<usize>, Vec<BranchConnection>>, DayError> {
@@ -263,7 +312,7 @@ impl ForestMap {
         let step = Step::create(self.start, Direction::South, first);
         let mut queue = vec![step];
         let mut all_connections = HashMap::new();
-        let mut seen = vec![];
+        let mut seen = HashSet::new();
         while let Some(current) = queue.pop() {
             let Some((connection, next_steps)) = self.walk_to_next_branch(&current)? else {
                 continue;
@@ -272,10 +321,9 @@ impl ForestMap {
                 .entry(current.start)
                 .and_modify(|lst: &mut Vec<BranchConnection>| lst.push(connection.clone()))
                 .or_insert(vec![connection.clone()]);
-            if seen.contains(&connection.end) {
+            if !seen.insert(connection.end) {
                 continue;
             }
-            seen.push(connection.end);
             for next_step in next_steps {
                 queue.push(next_step)
             }
@@ -288,14 +336,16 @@ impl FromStr for ForestMap {
     type Err = DayError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let map = s
-            .lines()
-            .map(|row| row.chars().map(Tile::try_from).try_collect())
-            .try_collect()?;
-        ForestMap::new(map)
+        ForestMap::new(parse_map(s)?)
     }
 }
 
+fn parse_map(s: &str) -> Result<Vec<Vec<Tile>>, DayError> {
+    s.lines()
+        .map(|row| row.chars().map(Tile::try_from).try_collect())
+        .try_collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -330,6 +380,7 @@ mod test {
         let input = read_string(day.get_day_number(), "example01.txt")?;
         let map: ForestMap = input.parse()?;
         assert_eq!(map.go_on_hike()?, 94);
+        assert_eq!(map.longest_path_count()?, (94, 1));
         Ok(())
     }
 
@@ -342,4 +393,57 @@ mod test {
         assert_eq!(map.go_on_hike()?, 154);
         Ok(())
     }
+
+    #[test]
+    fn connections() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let map: ForestMap = input.parse()?;
+        let connections = map.find_paths()?;
+
+        let expected = [
+            ((1, 0), vec![(3, 5, 15)]),
+            ((3, 5), vec![(5, 13, 22), (11, 3, 22)]),
+            ((11, 3), vec![(13, 13, 24), (21, 11, 30)]),
+            ((5, 13), vec![(13, 13, 12), (13, 19, 38)]),
+            ((13, 13), vec![(13, 19, 10), (21, 11, 18)]),
+            ((21, 11), vec![(19, 19, 10)]),
+            ((13, 19), vec![(19, 19, 10)]),
+            ((19, 19), vec![(21, 22, 5)]),
+        ];
+        assert_eq!(connections.len(), expected.len());
+        for ((x, y), ends) in expected {
+            let found = connections[&Pos2::new(x, y)]
+                .iter()
+                .map(|branch| (branch.end.x(), branch.end.y(), branch.steps))
+                .sorted()
+                .collect_vec();
+            assert_eq!(found, ends);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_start() -> UnitResult {
+        let input = "#.#.###\n#.#.###\n#...###\n###.###\n###.###\n###.###";
+        assert!(matches!(
+            input.parse::<ForestMap>(),
+            Err(DayError::MustHaveExactOneStartAndEnd)
+        ));
+
+        let map =
+            ForestMap::with_start_and_finish(parse_map(input)?, Pos2::new(1, 0), Pos2::new(3, 5))?;
+        assert_eq!(map.go_on_hike()?, 7);
+
+        let map =
+            ForestMap::with_start_and_finish(parse_map(input)?, Pos2::new(3, 0), Pos2::new(3, 5))?;
+        assert_eq!(map.go_on_hike()?, 5);
+
+        assert!(matches!(
+            ForestMap::with_start_and_finish(parse_map(input)?, Pos2::new(0, 0), Pos2::new(3, 5)),
+            Err(DayError::NotOnPath(_))
+        ));
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
-use crate::common::{area::Area, pos2::Pos2, pos3::Pos3};
+use crate::common::{area::Area, matrix2::Matrix2, pos2::Pos2, pos3::Pos3};
 
-use super::{DayTrait, DayType, RResult};
+use super::{DayTrait, DayType, RResult, UnitResult};
 use itertools::Itertools;
 use num_traits::Zero;
 use std::{num, str::FromStr};
@@ -19,9 +19,14 @@ impl DayTrait for Day {
         Ok(storm.count_collisions().into())
     }
 
-    fn part2(&self, input: &str) -> RResult {
+    fn part2(&self, _input: &str) -> RResult {
         Ok(().into())
     }
+
+    fn parse_only(&self, input: &str) -> UnitResult {
+        let _: Hailstorm = input.parse()?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -77,38 +82,58 @@ impl Hailstone {
             }
         }
 
-        let px1 = fst_pos.x();
-        let py1 = fst_pos.y();
-        let vx1 = fst_velocity.x();
-        let vy1 = fst_velocity.y();
-        let px2 = snd_pos.x();
-        let py2 = snd_pos.y();
-        let vx2 = snd_velocity.x();
-        let vy2 = snd_velocity.y();
-
-        let div = vx2 * vy1 - vy2 * vx1;
-        if div == 0.0 || vx1 == 0.0 {
-            return None;
-        }
+        // fst_pos + n * fst_velocity = snd_pos + m * snd_velocity
+        let matrix = Matrix2::from_col_vectors(fst_velocity, -snd_velocity);
+        let params = matrix.solve(snd_pos - fst_pos)?;
+        let n = params.x();
+        let m = params.y();
 
-        let m = ((py2 - py1) * vx1 - (px2 - px1) * vy1) / (vx2 * vy1 - vy2 * vx1);
-
-        if m == 0.0 {
-            return None;
-        }
-        let n = ((px2 - px1) + m * vx2) / vx1;
-        if n == 0.0 {
-            return None;
-        }
+        Some((snd_pos + snd_velocity * m, m, n))
+    }
 
-        let pos = Pos2::new(px2 + m * vx2, py2 + m * vy2);
-        Some((pos, m, n))
+    /// the points where the 3D paths of both stones come closest, at any time,
+    /// together with their distance
+    #[allow(dead_code)]
+    pub fn closest_approach_3d(&self, other: &Hailstone) -> (Pos3<f64>, Pos3<f64>, f64) {
+        let offset = other.position - self.position;
+        let normal = self.velocity.cross(other.velocity);
+        let norm = normal.dot(normal);
+
+        let (fst, snd) = if norm.is_zero() {
+            // parallel paths: any point on the first one will do
+            let m = -offset.dot(other.velocity) / other.velocity.dot(other.velocity);
+            (self.position, other.position + other.velocity * m)
+        } else {
+            // self.position + n * self.velocity = other.position + m * other.velocity
+            let n = offset.cross(other.velocity).dot(normal) / norm;
+            let m = offset.cross(self.velocity).dot(normal) / norm;
+            (
+                self.position + self.velocity * n,
+                other.position + other.velocity * m,
+            )
+        };
+        let between = snd - fst;
+        (fst, snd, between.dot(between).sqrt())
     }
 }
 
 struct Hailstorm {
     stones: Vec<Hailstone>,
     test: Area<f64>,
+    #[allow(dead_code)]
+    test_z: Option<(CoordType, CoordType)>,
+}
+
+impl Hailstorm {
+    /// parses a range `from-to`, where `from` might be negative itself
+    fn parse_range(range: &str) -> Result<(CoordType, CoordType), DayError> {
+        let range = range.trim();
+        let Some(split) = range.get(1..).and_then(|rest| rest.find('-')) else {
+            return Err(DayError::ParseError(range.to_owned()));
+        };
+        let (from, to) = range.split_at(split + 1);
+        Ok((from.parse()?, to[1..].parse()?))
+    }
 }
 
 impl FromStr for Hailstorm {
@@ -119,33 +144,26 @@ impl FromStr for Hailstorm {
         let Some(first) = lines.next() else {
             return Err(DayError::ParseError(s.to_owned()));
         };
-        let Some((x, y)) = first.split_once(',') else {
-            return Err(DayError::ParseError(first.to_owned()));
-        };
-        let Some((x_from, x_to)) = x.split_once('-') else {
-            return Err(DayError::ParseError(first.to_owned()));
+        let ranges: Vec<_> = first.split(',').map(Hailstorm::parse_range).try_collect()?;
+        let ((x_from, x_to), (y_from, y_to), test_z) = match ranges.as_slice() {
+            [x, y] => (*x, *y, None),
+            [x, y, z] => (*x, *y, Some(*z)),
+            _ => return Err(DayError::ParseError(first.to_owned())),
         };
-        let x_from = x_from.parse()?;
-        let x_to = x_to.parse()?;
-        let Some((y_from, y_to)) = y.split_once('-') else {
-            return Err(DayError::ParseError(first.to_owned()));
-        };
-        let y_from = y_from.parse()?;
-        let y_to = y_to.parse()?;
 
         let stones = lines.map(|line| line.parse()).try_collect()?;
 
         Ok(Self {
             stones,
             test: Area::create(Pos2::new(x_from, y_from), Pos2::new(x_to, y_to)).unwrap(),
+            test_z,
         })
     }
 }
 
 impl Hailstorm {
     fn conv_xy(from: Pos3<CoordType>) -> Pos2<f64> {
-        let p = from.project_xy();
-        Pos2::new(p.x() as f64, p.y() as f64)
+        from.project_xy()
     }
 
     pub fn count_collisions(&self) -> usize {
@@ -161,12 +179,7 @@ impl Hailstorm {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{
-        common::matrix3::Matrix3,
-        days::{read_string, ResultType, UnitResult},
-    };
-    use ndarray::prelude::*;
-    use ndarray_linalg::Solve;
+    use crate::days::{read_string, ResultType, UnitResult};
 
     #[test]
     fn test_part1() -> UnitResult {
@@ -210,15 +223,121 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn intersections() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let storm: Hailstorm = input.parse()?;
+
+        let Some((point, m, n)) =
+            storm.stones[0].intersects_2d(&storm.stones[1], Hailstorm::conv_xy)
+        else {
+            panic!("Found None")
+        };
+        assert!((point.x() - 43.0 / 3.0).abs() < 1e-9);
+        assert!((point.y() - 46.0 / 3.0).abs() < 1e-9);
+        assert!(m > 0.0 && n > 0.0);
+
+        // stones 1 and 2 move in parallel
+        assert!(storm.stones[1]
+            .intersects_2d(&storm.stones[2], Hailstorm::conv_xy)
+            .is_none());
+
+        // stone 0 crosses the path of stone 4 in its past
+        let Some((_, m, n)) = storm.stones[0].intersects_2d(&storm.stones[4], Hailstorm::conv_xy)
+        else {
+            panic!("Found None")
+        };
+        assert!(n < 0.0 && m > 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn degenerate_intersections() -> UnitResult {
+        let intersect = |fst: &str, snd: &str| -> anyhow::Result<_> {
+            let fst: Hailstone = fst.parse()?;
+            let snd: Hailstone = snd.parse()?;
+            Ok(fst.intersects_2d(&snd, Hailstorm::conv_xy))
+        };
+        let origin = Pos2::new(0.0, 0.0);
+
+        // parallel paths never cross, not even when they are the same
+        assert_eq!(intersect("0, 0, 0 @ 1, 1, 0", "1, 0, 0 @ 2, 2, 0")?, None);
+        assert_eq!(intersect("0, 0, 0 @ 1, 1, 0", "1, 1, 0 @ 2, 2, 0")?, None);
+
+        // crossing right at the start of one stone gives a parameter of 0
+        assert_eq!(
+            intersect("0, 0, 0 @ 1, 1, 0", "-1, 0, 0 @ 1, 0, 0")?,
+            Some((origin, 1.0, 0.0))
+        );
+        assert_eq!(
+            intersect("-1, 0, 0 @ 1, 0, 0", "0, 0, 0 @ 1, 1, 0")?,
+            Some((origin, 0.0, 1.0))
+        );
+
+        // a stone without any movement in x still meets others
+        assert_eq!(
+            intersect("0, 0, 0 @ 0, 1, 0", "-2, 3, 0 @ 1, 0, 0")?,
+            Some((Pos2::new(0.0, 3.0), 2.0, 3.0))
+        );
+
+        // a standing stone is only found at the same starting position,
+        // not when the other stone passes it later
+        assert_eq!(
+            intersect("0, 0, 0 @ 0, 0, 1", "0, 0, 5 @ 1, 1, 0")?,
+            Some((origin, 0.0, 0.0))
+        );
+        assert_eq!(intersect("1, 1, 0 @ 0, 0, 1", "0, 0, 0 @ 1, 1, 0")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn closest_approach() -> UnitResult {
+        let day = Day {};
+        let input = read_string(day.get_day_number(), "example01.txt")?;
+        let storm: Hailstorm = input.parse()?;
+
+        let (fst, snd) = (&storm.stones[0], &storm.stones[1]);
+        let (p1, p2, distance) = fst.closest_approach_3d(snd);
+        assert!(distance > 0.0);
+        assert!((distance - 32.0 / 29.0_f64.sqrt()).abs() < 1e-9);
+        assert!((p2 - p1).dot(fst.velocity).abs() < 1e-9);
+        assert!((p2 - p1).dot(snd.velocity).abs() < 1e-9);
+
+        // stones 1 and 2 move in parallel
+        let (_, _, distance) = storm.stones[1].closest_approach_3d(&storm.stones[2]);
+        let offset = storm.stones[2].position - storm.stones[1].position;
+        let expected = offset.cross(storm.stones[1].velocity);
+        let expected = (expected.dot(expected) / 6.0).sqrt();
+        assert!((distance - expected).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_header() -> UnitResult {
+        let storm: Hailstorm = "-5-10,-20--3\n19, 13, 30 @ -2,  1, -2".parse()?;
+        assert_eq!((storm.test.left(), storm.test.right()), (-5.0, 10.0));
+        assert_eq!((storm.test.top(), storm.test.bottom()), (-20.0, -3.0));
+        assert_eq!(storm.test_z, None);
+        assert_eq!(storm.stones.len(), 1);
+
+        let storm: Hailstorm = "7-27,7-27,-1-1".parse()?;
+        assert_eq!(storm.test_z, Some((-1.0, 1.0)));
+
+        assert!("7-27".parse::<Hailstorm>().is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn dummy() -> UnitResult {
         let day = Day {};
         let input = read_string(day.get_day_number(), "input.txt")?;
         let storm: Hailstorm = input.parse()?;
-
-        let s1 = &storm.stones[0];
-        let s2 = &storm.stones[1];
-        let s3 = &storm.stones[2];
+        assert!(storm.stones.len() >= 3);
 
         Ok(())
     }
@@ -1,5 +1,8 @@
 #![allow(dead_code)]
-use std::{fs, io};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fs, io,
+};
 
 use itertools::Itertools;
 
@@ -9,8 +12,10 @@ pub enum ResultType {
     #[default]
     Nothing,
     Integer(i64),
+    Pair(i64, i64),
     String(String),
     Lines(Vec<String>),
+    Grid(Vec<Vec<char>>),
 }
 
 pub type RResult = anyhow::Result<ResultType>;
@@ -37,6 +42,13 @@ impl From<Vec<String>> for ResultType {
     }
 }
 
+impl From<Vec<Vec<char>>> for ResultType {
+    #[inline]
+    fn from(grid: Vec<Vec<char>>) -> Self {
+        ResultType::Grid(grid)
+    }
+}
+
 impl From<Vec<Vec<bool>>> for ResultType {
     #[inline]
     fn from(lines: Vec<Vec<bool>>) -> Self {
@@ -77,6 +89,13 @@ impl From<i64> for ResultType {
     }
 }
 
+impl From<(i64, i64)> for ResultType {
+    #[inline]
+    fn from((first, second): (i64, i64)) -> Self {
+        ResultType::Pair(first, second)
+    }
+}
+
 impl From<usize> for ResultType {
     #[inline]
     fn from(value: usize) -> Self {
@@ -98,6 +117,11 @@ pub trait DayTrait {
     fn get_day_number(&self) -> DayType;
     fn part1(&self, input: &str) -> RResult;
     fn part2(&self, input: &str) -> RResult;
+
+    /// only parses the input, so that parsing can be timed on its own
+    fn parse_only(&self, _input: &str) -> UnitResult {
+        Ok(())
+    }
 }
 
 fn format_path(day_num: DayType, file: &str) -> String {
@@ -107,3 +131,102 @@ fn format_path(day_num: DayType, file: &str) -> String {
 pub fn read_string(day_num: DayType, file: &str) -> io::Result<String> {
     fs::read_to_string(format_path(day_num, file))
 }
+
+/// reads all files named exampleNN.txt of the given day, sorted by name
+pub fn read_examples(day_num: DayType) -> io::Result<Vec<(String, String)>> {
+    let mut names = vec![];
+    for entry in fs::read_dir(format_path(day_num, ""))? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if let Some(number) = name
+            .strip_prefix("example")
+            .and_then(|rest| rest.strip_suffix(".txt"))
+        {
+            if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+                names.push(name);
+            }
+        }
+    }
+    names.sort();
+    names
+        .into_iter()
+        .map(|name| read_string(day_num, &name).map(|content| (name, content)))
+        .collect()
+}
+
+/// keeps the content of every file read through it, so each file is read
+/// from disk only once. A single run reads every input just once anyway,
+/// this is meant for loops that read the same files over and over
+#[derive(Debug, Default)]
+pub struct InputCache {
+    files: HashMap<String, String>,
+    opened: usize,
+}
+
+impl InputCache {
+    /// like read_string, but uses the cached content if there is one
+    pub fn read_string(&mut self, day_num: DayType, file: &str) -> io::Result<&str> {
+        self.read_path(format_path(day_num, file))
+    }
+
+    fn read_path(&mut self, path: String) -> io::Result<&str> {
+        let content = match self.files.entry(path) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                self.opened += 1;
+                let content = fs::read_to_string(entry.key())?;
+                entry.insert(content)
+            }
+        };
+        Ok(content)
+    }
+
+    /// how many files were actually read from disk
+    pub fn opened(&self) -> usize {
+        self.opened
+    }
+
+    pub fn clear_cache(&mut self) {
+        self.files.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_all_examples() -> io::Result<()> {
+        let examples = read_examples(1)?;
+        let names: Vec<_> = examples.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["example01.txt", "example02.txt"]);
+        assert_eq!(examples[0].1, read_string(1, "example01.txt")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cached_input() -> UnitResult {
+        let path =
+            std::env::temp_dir().join(format!("advent2023_cache_{}.txt", std::process::id()));
+        let name = path.to_string_lossy().into_owned();
+        let mut cache = InputCache::default();
+
+        fs::write(&path, "first")?;
+        assert_eq!(cache.read_path(name.clone())?, "first");
+        assert_eq!(cache.opened(), 1);
+
+        // a second read must not go to disk again
+        fs::write(&path, "second")?;
+        assert_eq!(cache.read_path(name.clone())?, "first");
+        assert_eq!(cache.opened(), 1);
+
+        cache.clear_cache();
+        assert_eq!(cache.read_path(name.clone())?, "second");
+        assert_eq!(cache.opened(), 2);
+
+        fs::remove_file(&path)?;
+        assert_eq!(cache.read_path(name)?, "second");
+
+        Ok(())
+    }
+}
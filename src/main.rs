@@ -1,5 +1,4 @@
-#![feature(iter_partition_in_place)]
-#![feature(let_chains)]
+use colored::Colorize;
 use days::{day_provider, read_string, DayTrait, DayType, PartType, ResultType, UnitResult};
 use std::{env, time};
 
@@ -7,43 +6,122 @@ mod common;
 mod days;
 mod macros;
 
-fn output(day: DayType, part: PartType, result: ResultType, time: time::Duration) {
+fn colorize(cell: char) -> String {
+    match cell {
+        '.' | ' ' => cell.to_string().dimmed().to_string(),
+        '0'..='9' => cell.to_string().yellow().to_string(),
+        _ if cell.is_alphabetic() => cell.to_string().green().to_string(),
+        _ => cell.to_string().red().to_string(),
+    }
+}
+
+fn grid_lines(grid: &[Vec<char>], color: bool) -> Vec<String> {
+    grid.iter()
+        .map(|row| {
+            if color {
+                row.iter().map(|cell| colorize(*cell)).collect()
+            } else {
+                row.iter().collect()
+            }
+        })
+        .collect()
+}
+
+fn format_lines(
+    day: DayType,
+    part: PartType,
+    lines: &[String],
+    time: time::Duration,
+) -> Vec<String> {
+    let mut output = vec![format!(
+        "Day {:02} part {}: {} ({})",
+        day,
+        part,
+        lines[0],
+        time.as_secs_f32()
+    )];
+    output.extend(
+        lines[1..]
+            .iter()
+            .map(|line| format!("               {line}")),
+    );
+    output
+}
+
+fn format_output(
+    day: DayType,
+    part: PartType,
+    result: ResultType,
+    time: time::Duration,
+    color: bool,
+) -> Vec<String> {
     match result {
         ResultType::Integer(value) => {
-            println!(
+            vec![format!(
                 "Day {:02} part {}: {} ({})",
                 day,
                 part,
                 value,
                 time.as_secs_f64()
-            );
+            )]
         }
-        ResultType::String(value) => {
-            println!(
-                "Day {:02} part {}: {} ({})",
+        ResultType::Pair(first, second) => {
+            vec![format!(
+                "Day {:02} part {}: ({}, {}) ({})",
                 day,
                 part,
-                value,
-                time.as_secs_f32()
-            );
+                first,
+                second,
+                time.as_secs_f64()
+            )]
         }
-        ResultType::Lines(value) => {
-            println!(
+        ResultType::String(value) => {
+            vec![format!(
                 "Day {:02} part {}: {} ({})",
                 day,
                 part,
-                value[0],
+                value,
                 time.as_secs_f32()
-            );
-            for line in &value[1..] {
-                println!("               {line}");
-            }
+            )]
         }
-        ResultType::Nothing => {}
+        ResultType::Lines(value) => format_lines(day, part, &value, time),
+        ResultType::Grid(value) => format_lines(day, part, &grid_lines(&value, color), time),
+        ResultType::Nothing => vec![],
+    }
+}
+
+fn result_lines(
+    day: DayType,
+    part: PartType,
+    result: ResultType,
+    time: time::Duration,
+    options: &Options,
+) -> Vec<String> {
+    if matches!(result, ResultType::Nothing) && options.show_skipped {
+        vec![format!("Day {:02} part {}: (not implemented)", day, part)]
+    } else {
+        format_output(day, part, result, time, options.color)
+    }
+}
+
+fn output(
+    day: DayType,
+    part: PartType,
+    result: ResultType,
+    time: time::Duration,
+    options: &Options,
+) {
+    for line in result_lines(day, part, result, time, options) {
+        println!("{line}");
     }
 }
 
-fn run_part(day: &dyn DayTrait, is_part1: bool, input: &str) -> anyhow::Result<time::Duration> {
+fn run_part(
+    day: &dyn DayTrait,
+    is_part1: bool,
+    input: &str,
+    options: &Options,
+) -> anyhow::Result<time::Duration> {
     let now = time::Instant::now();
     let result = if is_part1 {
         day.part1(input)?
@@ -51,29 +129,54 @@ fn run_part(day: &dyn DayTrait, is_part1: bool, input: &str) -> anyhow::Result<t
         day.part2(input)?
     };
 
+    let part = if is_part1 { 1 } else { 2 };
     if matches!(result, ResultType::Nothing) {
-        Ok(time::Duration::ZERO)
-    } else {
-        let elapsed = now.elapsed();
         output(
             day.get_day_number(),
-            if is_part1 { 1 } else { 2 },
+            part,
             result,
-            elapsed,
+            time::Duration::ZERO,
+            options,
         );
+        Ok(time::Duration::ZERO)
+    } else {
+        let elapsed = now.elapsed();
+        output(day.get_day_number(), part, result, elapsed, options);
+        if let Some(threshold) = options.exceeded_threshold(elapsed) {
+            println!(
+                "Warning: Day {:02} part {} took longer than {}s",
+                day.get_day_number(),
+                part,
+                threshold.as_secs_f32()
+            );
+        }
         Ok(elapsed)
     }
 }
 
-fn run(day: &dyn DayTrait, part1: bool, part2: bool) -> anyhow::Result<time::Duration> {
-    let input = read_string(day.get_day_number(), "input.txt")?;
+fn run(
+    day: &dyn DayTrait,
+    part1: bool,
+    part2: bool,
+    options: &Options,
+) -> anyhow::Result<time::Duration> {
+    let input = read_string(day.get_day_number(), options.file())?;
+    if options.profile_parse {
+        let now = time::Instant::now();
+        day.parse_only(&input)?;
+        println!(
+            "Day {:02} parsing: ({})",
+            day.get_day_number(),
+            now.elapsed().as_secs_f64()
+        );
+    }
     let elapsed1 = if part1 {
-        run_part(day, true, &input)?
+        run_part(day, true, &input, options)?
     } else {
         time::Duration::ZERO
     };
     let elapsed2 = if part2 {
-        run_part(day, false, &input)?
+        run_part(day, false, &input, options)?
     } else {
         time::Duration::ZERO
     };
@@ -88,14 +191,76 @@ enum ParamError {
 
     #[error("Unknown Part: {0}")]
     UnknownPart(PartType),
+
+    #[error("Unknown Flag: {0}")]
+    UnknownFlag(String),
+
+    #[error("Missing value for flag: {0}")]
+    MissingValue(String),
+
+    #[error("Not a valid number of seconds: {0}")]
+    IllegalSeconds(String),
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    example: bool,
+    color: bool,
+    show_skipped: bool,
+    profile_parse: bool,
+    warn_over: Option<time::Duration>,
+}
+
+impl Options {
+    fn file(&self) -> &'static str {
+        if self.example {
+            "example01.txt"
+        } else {
+            "input.txt"
+        }
+    }
+
+    /// the threshold, if one is set and elapsed is above it
+    fn exceeded_threshold(&self, elapsed: time::Duration) -> Option<time::Duration> {
+        self.warn_over.filter(|threshold| elapsed > *threshold)
+    }
+}
+
+fn parse_params(params: &[String]) -> Result<(Options, Vec<&str>), ParamError> {
+    let mut options = Options::default();
+    let mut rest = vec![];
+    let mut params = params.iter();
+    while let Some(param) = params.next() {
+        match param.as_str() {
+            "--example" => options.example = true,
+            "--color" => options.color = true,
+            "--show-skipped" => options.show_skipped = true,
+            "--profile-parse" => options.profile_parse = true,
+            "--warn-over" => {
+                let Some(value) = params.next() else {
+                    return Err(ParamError::MissingValue(param.to_owned()));
+                };
+                let seconds = value
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|seconds| time::Duration::try_from_secs_f64(seconds).ok())
+                    .ok_or_else(|| ParamError::IllegalSeconds(value.to_owned()))?;
+                options.warn_over = Some(seconds);
+            }
+            flag if flag.starts_with("--") => Err(ParamError::UnknownFlag(flag.to_owned()))?,
+            param => rest.push(param),
+        }
+    }
+    Ok((options, rest))
 }
 
 fn run_on_parameters(params: &[String]) -> UnitResult {
+    let (options, params) = parse_params(params)?;
     match params.len() {
         0 => {
             let mut runtime = time::Duration::ZERO;
             for day in day_provider::get_all_days() {
-                runtime += run(day.as_ref(), true, true)?;
+                runtime += run(day.as_ref(), true, true, &options)?;
             }
             println!();
             println!("Runtime: {}", runtime.as_secs_f32());
@@ -108,12 +273,12 @@ fn run_on_parameters(params: &[String]) -> UnitResult {
 
                 if let Some(part_str) = parts.next() {
                     match part_str.parse::<PartType>()? {
-                        1 => run(day.as_ref(), true, false)?,
-                        2 => run(day.as_ref(), false, true)?,
+                        1 => run(day.as_ref(), true, false, &options)?,
+                        2 => run(day.as_ref(), false, true, &options)?,
                         p => Err(ParamError::UnknownPart(p))?,
                     };
                 } else {
-                    let runtime = run(day.as_ref(), true, true)?;
+                    let runtime = run(day.as_ref(), true, true, &options)?;
                     println!("Runtime: {}", runtime.as_secs_f32());
                 }
             }
@@ -127,3 +292,129 @@ fn main() -> UnitResult {
     let params = env::args().skip(1).collect::<Vec<_>>();
     run_on_parameters(&params)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_params(params: &[&str]) -> Vec<String> {
+        params.iter().map(|param| param.to_string()).collect()
+    }
+
+    #[test]
+    fn example_flag() -> UnitResult {
+        let params = to_params(&["19", "--example"]);
+        let (options, rest) = parse_params(&params)?;
+        assert!(options.example);
+        assert_eq!(options.file(), "example01.txt");
+        assert_eq!(rest, ["19"]);
+
+        let params = to_params(&["19/2"]);
+        let (options, rest) = parse_params(&params)?;
+        assert!(!options.example);
+        assert_eq!(options.file(), "input.txt");
+        assert_eq!(rest, ["19/2"]);
+
+        let params = to_params(&["--exampel"]);
+        assert!(matches!(
+            parse_params(&params),
+            Err(ParamError::UnknownFlag(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn color_flag() -> UnitResult {
+        let params = to_params(&["--color", "10"]);
+        let (options, rest) = parse_params(&params)?;
+        assert!(options.color);
+        assert!(!options.example);
+        assert_eq!(rest, ["10"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn warn_over_flag() -> UnitResult {
+        let params = to_params(&["--warn-over", "0.5", "7"]);
+        let (options, rest) = parse_params(&params)?;
+        assert_eq!(options.warn_over, Some(time::Duration::from_millis(500)));
+        assert_eq!(rest, ["7"]);
+
+        let fast = time::Duration::from_millis(100);
+        let slow = time::Duration::from_millis(700);
+        assert_eq!(options.exceeded_threshold(fast), None);
+        assert_eq!(
+            options.exceeded_threshold(time::Duration::from_millis(500)),
+            None
+        );
+        assert_eq!(
+            options.exceeded_threshold(slow),
+            Some(time::Duration::from_millis(500))
+        );
+        assert_eq!(Options::default().exceeded_threshold(slow), None);
+
+        assert!(matches!(
+            parse_params(&to_params(&["--warn-over"])),
+            Err(ParamError::MissingValue(_))
+        ));
+        assert!(matches!(
+            parse_params(&to_params(&["--warn-over", "-1"])),
+            Err(ParamError::IllegalSeconds(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn show_skipped() -> UnitResult {
+        let day = day_provider::get_day(24)?;
+        let result = day.part2("")?;
+        assert_eq!(result, ResultType::Nothing);
+
+        let time = time::Duration::ZERO;
+        let options = Options::default();
+        assert!(result_lines(24, 2, ResultType::Nothing, time, &options).is_empty());
+
+        let (options, _) = parse_params(&to_params(&["--show-skipped"]))?;
+        assert!(options.show_skipped);
+        assert_eq!(
+            result_lines(24, 2, result, time, &options),
+            ["Day 24 part 2: (not implemented)"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn profile_parse_flag() -> UnitResult {
+        let params = to_params(&["--profile-parse", "19"]);
+        let (options, rest) = parse_params(&params)?;
+        assert!(options.profile_parse);
+        assert_eq!(rest, ["19"]);
+        assert!(!Options::default().profile_parse);
+
+        Ok(())
+    }
+
+    #[test]
+    fn grid_output() {
+        let time = time::Duration::from_millis(5);
+        let grid = vec![vec!['#', '.', '#'], vec!['.', '#', '.']];
+        let lines = vec!["#.#".to_owned(), ".#.".to_owned()];
+
+        let grid_output = format_output(3, 1, ResultType::Grid(grid), time, false);
+        let lines_output = format_output(3, 1, ResultType::Lines(lines), time, false);
+        assert_eq!(grid_output, lines_output);
+        assert_eq!(grid_output[0], "Day 03 part 1: #.# (0.005)");
+        assert_eq!(grid_output[1], "               .#.");
+    }
+
+    #[test]
+    fn pair_output() {
+        let time = time::Duration::from_millis(5);
+        let output = format_output(9, 1, ResultType::Pair(5, 68), time, false);
+        assert_eq!(output, ["Day 09 part 1: (5, 68) (0.005)"]);
+    }
+}